@@ -1,5 +1,7 @@
 pub const DAY_OF_LEDGERS: u32 = 60 * 60 * 24 / 5; // assumes 5 second ledger close times
 pub const WEEK_OF_LEDGERS: u32 = DAY_OF_LEDGERS * 7;
 
-pub const MAXIMUM_TOKENS_TO_BE_MINTED: u32 = 250;
-pub const MAXIMUM_TOKENS_PER_ADDRESS: u32 = 5;
+pub const DEFAULT_MAX_SUPPLY: u32 = 250; // the default maximum number of tokens ever to be minted
+pub const DEFAULT_MAX_PER_ADDRESS: u32 = 5; // the default maximum number of tokens a single address can hold
+
+pub const MAXIMUM_URI_LENGTH: usize = 256;
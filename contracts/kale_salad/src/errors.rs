@@ -5,12 +5,26 @@ use soroban_sdk::contracterror;
 #[repr(u32)]
 pub enum Errors {
     UnsetMetadata = 101,
+    /// Indicates a token URI longer than the contract is able to build. Used in URI setters.
+    UriTooLong = 102,
     /// Indicates an error related to the payment vegetables. Used in `__constructor`.
     TooFewVegetables = 104,
+    /// Indicates a vegetable which is not part of the salad. Used in `mint_custom_salad`.
+    InvalidVegetable = 105,
+    /// Indicates an amount of vegetable outside of its configured limits. Used in `mint_custom_salad`.
+    InvalidProportion = 106,
+    /// Indicates a custom salad whose total payment is below the price floor. Used in `mint_custom_salad`.
+    InsufficientPayment = 107,
+    /// Indicates a price floor of zero or less. Used in `set_custom_price_floor`.
+    InvalidPriceFloor = 108,
     /// Indicates an error related to the number of tokens a single address can hold.
     AllTokensMinted = 201,
     /// Indicates an error related to the number of tokens ever to be minted.
     MaxTokensReached = 202,
+    /// Indicates a maximum supply lower than the number of tokens already minted. Used in `set_max_supply`.
+    SupplyBelowMinted = 203,
+    /// Indicates a per-address maximum of zero. Used in `set_max_per_address` and `__constructor`.
+    InvalidMaxPerAddress = 204,
     /// Indicates a non-existent `token_id`.
     NonExistentToken = 300,
     /// Indicates an error related to the ownership over a particular token. Used in `transfer`s and approvals.
@@ -19,6 +33,12 @@ pub enum Errors {
     InsufficientApproval = 302,
     /// Indicates a failure with the `spender` of a token to be approved. Used in approvals.
     InvalidSpender = 303,
-    /// Indicates an invalid value for `expiration_edger` when setting approvals.
+    /// Indicates an invalid value for `expiration_edger` when setting approvals or proposing an admin.
     InvalidExpirationLedger = 304,
+    /// Indicates an index past the end of the existing tokens. Used in `token_by_index`.
+    IndexOutOfBounds = 305,
+    /// Indicates an action which the admin has currently paused. Used in mints, `transfer`s, and `burn`s.
+    ActionPaused = 401,
+    /// Indicates there is no unexpired admin proposal to act on. Used in `accept_admin` and `cancel_admin_proposal`.
+    NoPendingAdmin = 501,
 }
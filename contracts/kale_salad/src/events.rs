@@ -1,6 +1,6 @@
 use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
-use crate::types::Storage;
+use crate::types::{PauseState, SaladRecipe, Storage};
 
 pub fn emit_transfer(env: &Env, from: &Address, to: &Address, token_id: &u32) {
     let topics = (symbol_short!("transfer"), from, to);
@@ -28,8 +28,43 @@ pub fn emit_burn(env: &Env, owner: &Address, token_id: &u32) {
     env.events().publish(topics, token_id);
 }
 
-pub fn emit_mint(env: &Env, owner: &Address, token_id: &u32) {
+pub fn emit_mint(env: &Env, owner: &Address, token_id: &u32, recipe: &SaladRecipe) {
     let admin: Address = env.storage().instance().get(&Storage::Admin).unwrap();
     let topics = (symbol_short!("mint"), &admin, owner);
-    env.events().publish(topics, token_id);
+    env.events().publish(topics, (token_id, recipe.clone()));
+}
+
+pub fn emit_pause(env: &Env, admin: &Address, state: &PauseState) {
+    let topics = (symbol_short!("pause"), admin);
+    env.events().publish(topics, state.clone());
+}
+
+pub fn emit_max_supply(env: &Env, admin: &Address, max_supply: &u32) {
+    let topics = (Symbol::new(env, "max_supply"), admin);
+    env.events().publish(topics, max_supply);
+}
+
+pub fn emit_max_per_address(env: &Env, admin: &Address, max_per_address: &u32) {
+    let topics = (Symbol::new(env, "max_per_address"), admin);
+    env.events().publish(topics, max_per_address);
+}
+
+pub fn emit_propose_admin(
+    env: &Env,
+    admin: &Address,
+    new_admin: &Address,
+    expiration_ledger: &u32,
+) {
+    let topics = (Symbol::new(env, "propose_admin"), admin);
+    env.events().publish(topics, (new_admin, expiration_ledger));
+}
+
+pub fn emit_accept_admin(env: &Env, old_admin: &Address, new_admin: &Address) {
+    let topics = (Symbol::new(env, "accept_admin"), old_admin);
+    env.events().publish(topics, new_admin);
+}
+
+pub fn emit_cancel_admin(env: &Env, admin: &Address, cancelled_admin: &Address) {
+    let topics = (Symbol::new(env, "cancel_admin"), admin);
+    env.events().publish(topics, cancelled_admin);
 }
@@ -1,22 +1,32 @@
 #![no_std]
 
-use constants::{MAXIMUM_TOKENS_PER_ADDRESS, MAXIMUM_TOKENS_TO_BE_MINTED};
+use constants::{DEFAULT_MAX_PER_ADDRESS, DEFAULT_MAX_SUPPLY, MAXIMUM_URI_LENGTH};
 use errors::Errors;
-use events::{emit_approve, emit_approve_all, emit_burn, emit_mint, emit_transfer};
-use soroban_nft_interface::NonFungibleTokenInterface;
+use events::{
+    emit_accept_admin, emit_approve, emit_approve_all, emit_burn, emit_cancel_admin,
+    emit_max_per_address, emit_max_supply, emit_mint, emit_pause, emit_propose_admin,
+    emit_transfer,
+};
+use soroban_nft_interface::{NonFungibleTokenEnumerable, NonFungibleTokenInterface};
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, panic_with_error, token, Address, BytesN, Env, String,
-    Vec,
+    contract, contractimpl, contractmeta, panic_with_error, token, Address, BytesN, Env, Map,
+    String, Vec,
 };
 use storage::{
-    add_token, burn_token, clear_approved_all_data, clear_approved_data, decrement_supply,
-    extend_instance_ttl, get_admin, get_approved_all_data, get_approved_data, get_metadata,
-    get_mint_index, get_payment_per_nft, get_supply, get_token_owner, get_tokens_owned,
-    get_vegetables, is_token_owner, set_admin, set_approved_all_data, set_approved_data,
-    set_metadata, set_mint_index, set_payment_per_nft, set_supply, set_token_owner,
-    set_tokens_owned, set_vegetables, spend_token, spender_is_approved, token_exists,
+    add_token, burn_token, clear_approved_all_data, clear_approved_data, clear_pending_admin,
+    clear_token_uri, decrement_supply, extend_instance_ttl, get_admin, get_approved_all_data,
+    get_approved_data, get_custom_price_floor, get_max_per_address, get_max_supply, get_metadata,
+    get_mint_index, get_pause_state, get_payment_per_nft, get_pending_admin, get_salad_recipe,
+    get_supply, get_token_by_index, get_token_owner, get_token_uri, get_tokens_owned,
+    get_uri_suffix, get_vegetable_limits, get_vegetables, is_token_owner, set_admin,
+    set_approved_all_data, set_approved_data, set_custom_price_floor, set_max_per_address,
+    set_max_supply, set_metadata, set_mint_index, set_pause_state, set_payment_per_nft,
+    set_pending_admin, set_salad_recipe, set_supply, set_token_by_index, set_token_owner,
+    set_token_uri, set_tokens_owned, set_uri_suffix, set_vegetable_limits, set_vegetables,
+    spend_token, spender_is_approved, token_exists,
 };
-use utils::u8_to_string;
+use types::{PauseState, PendingAdmin, SaladRecipe, SupplyLimits, VegetableLimits};
+use utils::{build_token_uri, check_uri_fits};
 
 contractmeta!(key = "title", val = "KALE Salad");
 contractmeta!(
@@ -38,6 +48,7 @@ pub struct KaleSaladContract;
 
 #[contractimpl]
 impl KaleSaladContract {
+    #[allow(clippy::too_many_arguments)]
     pub fn __constructor(
         env: Env,
         admin: Address,
@@ -46,11 +57,14 @@ impl KaleSaladContract {
         base_uri: String,
         vegetables: Vec<Address>,
         payment_each_vegetable: i128,
+        supply_limits: Option<SupplyLimits>,
     ) {
         if vegetables.len() < 4 {
             panic_with_error!(&env, Errors::TooFewVegetables);
         }
 
+        check_uri_fits::<MAXIMUM_URI_LENGTH>(&env, &base_uri, &String::from_str(&env, ""));
+
         admin.require_auth();
 
         set_admin(&env, &admin);
@@ -62,6 +76,17 @@ impl KaleSaladContract {
         set_supply(&env, &0u32);
         set_mint_index(&env, &0u32);
 
+        // set the minting limits, using the defaults if not provided
+        let supply_limits = supply_limits.unwrap_or(SupplyLimits {
+            max_supply: DEFAULT_MAX_SUPPLY,
+            max_per_address: DEFAULT_MAX_PER_ADDRESS,
+        });
+        if supply_limits.max_per_address == 0 {
+            panic_with_error!(&env, Errors::InvalidMaxPerAddress);
+        }
+        set_max_supply(&env, &supply_limits.max_supply);
+        set_max_per_address(&env, &supply_limits.max_per_address);
+
         // extend the contract's TTL
         extend_instance_ttl(&env);
     }
@@ -78,6 +103,270 @@ impl KaleSaladContract {
         extend_instance_ttl(&env);
     }
 
+    /// Propose a new admin for the contract. The proposed address has until
+    /// `expiration_ledger` to accept the role with `accept_admin`, after which
+    /// the proposal lapses. Proposing again replaces any earlier proposal.
+    ///
+    /// # Arguments
+    ///
+    /// - `new_admin` - The address proposed to become the admin.
+    /// - `expiration_ledger` - The last ledger on which the proposal can be
+    ///   accepted.
+    ///
+    /// # Panics
+    ///
+    /// - If `expiration_ledger` is less than the current ledger number, or
+    ///   further ahead than temporary storage can keep the proposal
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["propose_admin", admin: Address]`
+    /// - data - `[new_admin: Address, expiration_ledger: u32]`
+    pub fn propose_admin(env: Env, new_admin: Address, expiration_ledger: u32) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if expiration_ledger < env.ledger().sequence()
+            || expiration_ledger - env.ledger().sequence() > env.storage().max_ttl()
+        {
+            panic_with_error!(&env, Errors::InvalidExpirationLedger);
+        }
+
+        set_pending_admin(&env, &new_admin, &expiration_ledger);
+
+        emit_propose_admin(&env, &admin, &new_admin, &expiration_ledger);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Accept a pending admin proposal, becoming the contract's admin.
+    /// Authorized by the proposed address.
+    ///
+    /// # Panics
+    ///
+    /// - If there is no pending proposal, or it has expired
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["accept_admin", old_admin: Address]`
+    /// - data - `new_admin: Address`
+    pub fn accept_admin(env: Env) {
+        let pending_admin = get_pending_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Errors::NoPendingAdmin));
+        pending_admin.address.require_auth();
+
+        let old_admin = get_admin(&env);
+        set_admin(&env, &pending_admin.address);
+        clear_pending_admin(&env);
+
+        emit_accept_admin(&env, &old_admin, &pending_admin.address);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Withdraw a pending admin proposal before it is accepted.
+    ///
+    /// # Panics
+    ///
+    /// - If there is no pending proposal, or it has expired
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["cancel_admin", admin: Address]`
+    /// - data - `cancelled_admin: Address`
+    pub fn cancel_admin_proposal(env: Env) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let pending_admin = get_pending_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Errors::NoPendingAdmin));
+        clear_pending_admin(&env);
+
+        emit_cancel_admin(&env, &admin, &pending_admin.address);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    pub fn admin(env: Env) -> Address {
+        get_admin(&env)
+    }
+
+    pub fn pending_admin(env: Env) -> Option<PendingAdmin> {
+        get_pending_admin(&env)
+    }
+
+    /// Change the maximum number of NFTs which can ever be minted.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_supply` - The new maximum number of NFTs.
+    ///
+    /// # Panics
+    ///
+    /// - If more NFTs than `max_supply` have already been minted.
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["max_supply", admin: Address]`
+    /// - data - `max_supply: u32`
+    pub fn set_max_supply(env: Env, max_supply: u32) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if max_supply < get_mint_index(&env) {
+            panic_with_error!(&env, Errors::SupplyBelowMinted);
+        }
+
+        set_max_supply(&env, &max_supply);
+
+        emit_max_supply(&env, &admin, &max_supply);
+    }
+
+    pub fn max_supply(env: Env) -> u32 {
+        get_max_supply(&env)
+    }
+
+    /// Change the maximum number of NFTs a single address can hold. Addresses
+    /// already holding more than a lowered maximum keep their NFTs, but cannot
+    /// mint or receive any more.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_per_address` - The new maximum number of NFTs per address.
+    ///
+    /// # Panics
+    ///
+    /// - If `max_per_address` is zero.
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["max_per_address", admin: Address]`
+    /// - data - `max_per_address: u32`
+    pub fn set_max_per_address(env: Env, max_per_address: u32) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if max_per_address == 0 {
+            panic_with_error!(&env, Errors::InvalidMaxPerAddress);
+        }
+
+        set_max_per_address(&env, &max_per_address);
+
+        emit_max_per_address(&env, &admin, &max_per_address);
+    }
+
+    pub fn max_per_address(env: Env) -> u32 {
+        get_max_per_address(&env)
+    }
+
+    /// Pause or resume the minting of new NFTs.
+    ///
+    /// # Arguments
+    ///
+    /// - `paused` - Whether minting should be paused.
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["pause", admin: Address]`
+    /// - data - `state: PauseState`
+    pub fn set_mint_paused(env: Env, paused: bool) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let mut state = get_pause_state(&env);
+        state.minting = paused;
+        set_pause_state(&env, &state);
+
+        emit_pause(&env, &admin, &state);
+    }
+
+    /// Pause or resume `transfer` and `transfer_from`.
+    ///
+    /// # Arguments
+    ///
+    /// - `paused` - Whether transfers should be paused.
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["pause", admin: Address]`
+    /// - data - `state: PauseState`
+    pub fn set_transfer_paused(env: Env, paused: bool) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let mut state = get_pause_state(&env);
+        state.transfers = paused;
+        set_pause_state(&env, &state);
+
+        emit_pause(&env, &admin, &state);
+    }
+
+    /// Pause or resume `burn` and `burn_from`.
+    ///
+    /// # Arguments
+    ///
+    /// - `paused` - Whether burns should be paused.
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["pause", admin: Address]`
+    /// - data - `state: PauseState`
+    pub fn set_burn_paused(env: Env, paused: bool) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let mut state = get_pause_state(&env);
+        state.burns = paused;
+        set_pause_state(&env, &state);
+
+        emit_pause(&env, &admin, &state);
+    }
+
+    /// Emergency stop: pause minting, transfers, and burns all at once. Each
+    /// can be resumed individually afterward.
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["pause", admin: Address]`
+    /// - data - `state: PauseState`
+    pub fn pause_all(env: Env) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let state = PauseState {
+            minting: true,
+            transfers: true,
+            burns: true,
+        };
+        set_pause_state(&env, &state);
+
+        emit_pause(&env, &admin, &state);
+    }
+
+    pub fn pause_state(env: Env) -> PauseState {
+        get_pause_state(&env)
+    }
+
     pub fn set_price(env: Env, payment_each_vegetable: i128) {
         let admin = get_admin(&env);
         admin.require_auth();
@@ -98,6 +387,7 @@ impl KaleSaladContract {
     ///
     /// # Panics
     ///
+    /// - If minting has been paused by the admin.
     /// - If the payment amount does not meet the required minimum.
     /// - If the total maximum number of NFTs has already been minted.
     /// - If the requested number of NFTs would exceed the total maximum NFTs.
@@ -108,57 +398,188 @@ impl KaleSaladContract {
     ///
     /// - Emits an event with:
     /// - topics - `["mint", admin: Address, owner: Address]`
-    /// - data - `token_id: u32`
+    /// - data - `[token_id: u32, recipe: SaladRecipe]`
     pub fn mint_salad(env: Env, owner: Address, number_of_tokens: Option<u32>) {
         owner.require_auth();
 
-        let num_tokens: u32 = match number_of_tokens {
-            Some(num) => num,
-            _ => 1,
-        };
+        // stop here if minting has been paused
+        if get_pause_state(&env).minting {
+            panic_with_error!(&env, Errors::ActionPaused);
+        }
+
+        let num_tokens: u32 = number_of_tokens.unwrap_or(1);
 
-        let mut mint_index = get_mint_index(&env);
-        if mint_index + num_tokens > MAXIMUM_TOKENS_TO_BE_MINTED {
-            panic_with_error!(&env, Errors::AllTokensMinted);
+        let payment_per_nft = get_payment_per_nft(&env);
+        let vegetables = get_vegetables(&env);
+
+        // an equal share of the payment goes to each vegetable
+        let vegetable_per_nft = payment_per_nft / vegetables.len() as i128;
+        let payment_each_vegetable = vegetable_per_nft * num_tokens as i128;
+
+        let mut ingredients: Map<Address, i128> = Map::new(&env);
+        for vegetable in vegetables.iter() {
+            ingredients.set(vegetable, vegetable_per_nft);
         }
 
-        if num_tokens > MAXIMUM_TOKENS_PER_ADDRESS {
-            panic_with_error!(&env, Errors::MaxTokensReached);
+        let recipe = SaladRecipe {
+            ingredients,
+            mint_ledger: env.ledger().sequence(),
+        };
+        mint_tokens(&env, &owner, num_tokens, &recipe);
+
+        // burn the payment amount from each vegetable
+        for vegetable in vegetables {
+            let vegetable_client = token::Client::new(&env, &vegetable);
+            vegetable_client.burn(&owner, &payment_each_vegetable);
         }
+    }
 
-        let mut tokens_owned = get_tokens_owned(&env, &owner);
-        let future_balance = tokens_owned.len() + num_tokens;
-        if future_balance > MAXIMUM_TOKENS_PER_ADDRESS {
-            panic_with_error!(&env, Errors::MaxTokensReached);
+    /// Mint a KALE Salad NFT to the balance of `owner`, choosing how much of
+    /// each vegetable goes into it.
+    ///
+    /// # Arguments
+    ///
+    /// - `owner` - The address which will own the minted NFT
+    /// - `proportions` - A map of vegetable addresses to the amount (in
+    ///   stroops) of that vegetable the owner is paying. Vegetables left out
+    ///   of the map are treated as an amount of 0.
+    ///
+    /// # Panics
+    ///
+    /// - If minting has been paused by the admin.
+    /// - If a vegetable in `proportions` is not one of the salad vegetables.
+    /// - If the amount of any vegetable is outside of its configured limits.
+    /// - If the total payment amount does not meet the custom price floor.
+    /// - If the total maximum number of NFTs has already been minted.
+    /// - If the NFT would exceed the maximum NFTs allowed per address.
+    ///
+    /// # Events
+    ///
+    /// - Emits an event with:
+    /// - topics - `["mint", admin: Address, owner: Address]`
+    /// - data - `[token_id: u32, recipe: SaladRecipe]`
+    pub fn mint_custom_salad(env: Env, owner: Address, proportions: Map<Address, i128>) {
+        owner.require_auth();
+
+        // stop here if minting has been paused
+        if get_pause_state(&env).minting {
+            panic_with_error!(&env, Errors::ActionPaused);
         }
 
-        let payment_per_nft = get_payment_per_nft(&env);
         let vegetables = get_vegetables(&env);
 
-        let payment_each_vegetable = payment_per_nft / 4 * num_tokens as i128;
+        // make sure we're only being asked for vegetables we know about
+        for vegetable in proportions.keys() {
+            if !vegetables.contains(&vegetable) {
+                panic_with_error!(&env, Errors::InvalidVegetable);
+            }
+        }
 
-        // burn the payment amount from each vegetable
+        let mut ingredients: Map<Address, i128> = Map::new(&env);
+        let mut total_payment: i128 = 0;
         for vegetable in vegetables {
+            let amount = proportions.get(vegetable.clone()).unwrap_or(0);
+
+            // make sure the amount is within this vegetable's limits
+            let limits = get_vegetable_limits(&env, &vegetable);
+            if amount < 0 || amount < limits.minimum || amount > limits.maximum {
+                panic_with_error!(&env, Errors::InvalidProportion);
+            }
+
+            if amount > 0 {
+                ingredients.set(vegetable, amount);
+                total_payment += amount;
+            }
+        }
+
+        if total_payment < get_custom_price_floor(&env) {
+            panic_with_error!(&env, Errors::InsufficientPayment);
+        }
+
+        let recipe = SaladRecipe {
+            ingredients,
+            mint_ledger: env.ledger().sequence(),
+        };
+        mint_tokens(&env, &owner, 1, &recipe);
+
+        // burn the chosen amount of each vegetable
+        for (vegetable, amount) in recipe.ingredients.iter() {
             let vegetable_client = token::Client::new(&env, &vegetable);
-            vegetable_client.burn(&owner, &payment_each_vegetable);
+            vegetable_client.burn(&owner, &amount);
+        }
+    }
+
+    /// Configure how much of a vegetable can go into a custom salad.
+    ///
+    /// # Arguments
+    ///
+    /// - `vegetable` - The address of the salad vegetable to configure.
+    /// - `minimum` - The smallest amount (in stroops) of this vegetable a
+    ///   custom salad must contain.
+    /// - `maximum` - The largest amount (in stroops) of this vegetable a custom
+    ///   salad may contain.
+    ///
+    /// # Panics
+    ///
+    /// - If `vegetable` is not one of the salad vegetables.
+    /// - If `minimum` is negative, or greater than `maximum`.
+    pub fn set_vegetable_limits(env: Env, vegetable: Address, minimum: i128, maximum: i128) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if !get_vegetables(&env).contains(&vegetable) {
+            panic_with_error!(&env, Errors::InvalidVegetable);
+        }
+
+        if minimum < 0 || minimum > maximum {
+            panic_with_error!(&env, Errors::InvalidProportion);
         }
 
-        let supply = get_supply(&env);
+        set_vegetable_limits(&env, &vegetable, &VegetableLimits { minimum, maximum });
+    }
+
+    pub fn vegetable_limits(env: Env, vegetable: Address) -> VegetableLimits {
+        get_vegetable_limits(&env, &vegetable)
+    }
+
+    /// Configure the minimum total payment (in stroops) for a custom salad.
+    ///
+    /// # Panics
+    ///
+    /// - If `floor` is zero or negative.
+    pub fn set_custom_price_floor(env: Env, floor: i128) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
 
-        for _ in 0..num_tokens {
-            tokens_owned.push_back(mint_index);
-            set_token_owner(&env, &mint_index, &owner);
+        if floor <= 0 {
+            panic_with_error!(&env, Errors::InvalidPriceFloor);
+        }
 
-            // emit an event for this token's minting
-            emit_mint(&env, &owner, &mint_index);
+        set_custom_price_floor(&env, &floor);
+    }
+
+    pub fn custom_price_floor(env: Env) -> i128 {
+        get_custom_price_floor(&env)
+    }
 
-            // increment the mint index
-            mint_index += 1;
+    /// Find out what went into a KALE Salad NFT.
+    ///
+    /// # Arguments
+    ///
+    /// - `token_id` - The NFT to get the recipe for.
+    ///
+    /// # Panics
+    ///
+    /// - If the NFT does not exist
+    pub fn salad_recipe(env: Env, token_id: u32) -> SaladRecipe {
+        // if the token_id does not exist, panic!
+        if !token_exists(&env, &token_id) {
+            panic_with_error!(&env, Errors::NonExistentToken);
         }
 
-        set_supply(&env, &(supply + num_tokens));
-        set_mint_index(&env, &mint_index);
-        set_tokens_owned(&env, &owner, &tokens_owned);
+        get_salad_recipe(&env, &token_id)
     }
 
     pub fn set_base_uri(env: Env, base_uri: String) {
@@ -166,6 +587,9 @@ impl KaleSaladContract {
         let admin = get_admin(&env);
         admin.require_auth();
 
+        // make sure every token's URI can still be built
+        check_uri_fits::<MAXIMUM_URI_LENGTH>(&env, &base_uri, &get_uri_suffix(&env));
+
         let metadata = get_metadata(&env);
         set_metadata(&env, metadata.name, metadata.symbol, base_uri);
     }
@@ -174,13 +598,88 @@ impl KaleSaladContract {
         get_metadata(&env).base_uri
     }
 
+    /// Set a suffix (e.g., `.json`) to be appended after the token id in every
+    /// token URI.
+    ///
+    /// # Panics
+    ///
+    /// - If a URI made from the base URI, the largest possible token id, and
+    ///   the suffix would be too long for the contract to build.
+    pub fn set_uri_suffix(env: Env, suffix: String) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        // make sure every token's URI can still be built
+        check_uri_fits::<MAXIMUM_URI_LENGTH>(&env, &get_metadata(&env).base_uri, &suffix);
+
+        set_uri_suffix(&env, &suffix);
+    }
+
+    pub fn uri_suffix(env: Env) -> String {
+        get_uri_suffix(&env)
+    }
+
+    /// Override the URI for a single NFT, instead of building it from the base
+    /// URI and suffix.
+    ///
+    /// # Arguments
+    ///
+    /// - `token_id` - The NFT to set the URI for.
+    /// - `uri` - The complete URI for this NFT.
+    ///
+    /// # Panics
+    ///
+    /// - If the NFT does not exist
+    /// - If the URI is longer than the contract's maximum URI length
+    pub fn set_token_uri(env: Env, token_id: u32, uri: String) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        // if the token_id does not exist, panic!
+        if !token_exists(&env, &token_id) {
+            panic_with_error!(&env, Errors::NonExistentToken);
+        }
+
+        if uri.len() as usize > MAXIMUM_URI_LENGTH {
+            panic_with_error!(&env, Errors::UriTooLong);
+        }
+
+        set_token_uri(&env, &token_id, &uri);
+    }
+
+    /// Remove an NFT's URI override, so its URI is once again built from the
+    /// base URI and suffix.
+    ///
+    /// # Panics
+    ///
+    /// - If the NFT does not exist
+    pub fn remove_token_uri(env: Env, token_id: u32) {
+        // require authorization from the admin address.
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        // if the token_id does not exist, panic!
+        if !token_exists(&env, &token_id) {
+            panic_with_error!(&env, Errors::NonExistentToken);
+        }
+
+        clear_token_uri(&env, &token_id);
+    }
+
     pub fn decimals() -> u32 {
-        return 0u32;
+        0u32
     }
 
     pub fn burn(env: Env, owner: Address, token_id: u32) {
         owner.require_auth();
 
+        // stop here if burns have been paused
+        if get_pause_state(&env).burns {
+            panic_with_error!(&env, Errors::ActionPaused);
+        }
+
         // if the token_id does not exist, panic
         if !token_exists(&env, &token_id) {
             panic_with_error!(&env, Errors::NonExistentToken);
@@ -203,6 +702,11 @@ impl KaleSaladContract {
     pub fn burn_from(env: Env, spender: Address, owner: Address, token_id: u32) {
         spender.require_auth();
 
+        // stop here if burns have been paused
+        if get_pause_state(&env).burns {
+            panic_with_error!(&env, Errors::ActionPaused);
+        }
+
         // if the token_id does not exist, panic
         if !token_exists(&env, &token_id) {
             panic_with_error!(&env, Errors::NonExistentToken);
@@ -228,11 +732,51 @@ impl KaleSaladContract {
     }
 }
 
+/// Checks the minting limits and records `num_tokens` new NFTs, all made from
+/// the same `recipe`, in the balance of `owner`.
+fn mint_tokens(env: &Env, owner: &Address, num_tokens: u32, recipe: &SaladRecipe) {
+    let mut mint_index = get_mint_index(env);
+    if mint_index + num_tokens > get_max_supply(env) {
+        panic_with_error!(env, Errors::AllTokensMinted);
+    }
+
+    let max_per_address = get_max_per_address(env);
+    if num_tokens > max_per_address {
+        panic_with_error!(env, Errors::MaxTokensReached);
+    }
+
+    let mut tokens_owned = get_tokens_owned(env, owner);
+    let future_balance = tokens_owned.len() + num_tokens;
+    if future_balance > max_per_address {
+        panic_with_error!(env, Errors::MaxTokensReached);
+    }
+
+    let mut supply = get_supply(env);
+
+    for _ in 0..num_tokens {
+        tokens_owned.push_back(mint_index);
+        set_token_by_index(env, &supply, &mint_index);
+        set_token_owner(env, &mint_index, owner);
+        set_salad_recipe(env, &mint_index, recipe);
+
+        // emit an event for this token's minting
+        emit_mint(env, owner, &mint_index, recipe);
+
+        // increment the mint index and supply
+        mint_index += 1;
+        supply += 1;
+    }
+
+    set_supply(env, &supply);
+    set_mint_index(env, &mint_index);
+    set_tokens_owned(env, owner, &tokens_owned);
+}
+
 #[contractimpl]
 impl NonFungibleTokenInterface for KaleSaladContract {
     fn balance(env: Env, id: Address) -> u32 {
         let tokens_owned = get_tokens_owned(&env, &id);
-        return tokens_owned.len();
+        tokens_owned.len()
     }
 
     fn owner_of(env: Env, token_id: u32) -> Address {
@@ -241,7 +785,7 @@ impl NonFungibleTokenInterface for KaleSaladContract {
             panic_with_error!(&env, Errors::NonExistentToken);
         }
 
-        return get_token_owner(&env, &token_id);
+        get_token_owner(&env, &token_id)
     }
 
     fn approve(env: Env, owner: Address, spender: Address, token_id: u32, expiration_ledger: u32) {
@@ -295,11 +839,7 @@ impl NonFungibleTokenInterface for KaleSaladContract {
             panic_with_error!(&env, Errors::NonExistentToken);
         }
 
-        if let Some(approval_data) = get_approved_data(&env, &token_id) {
-            return Some(approval_data.spender);
-        } else {
-            return None;
-        }
+        get_approved_data(&env, &token_id).map(|approval_data| approval_data.spender)
     }
 
     fn approve_all(env: Env, owner: Address, spender: Address, expiration_ledger: u32) {
@@ -338,15 +878,20 @@ impl NonFungibleTokenInterface for KaleSaladContract {
 
     fn is_approved_all(env: Env, owner: Address, spender: Address) -> bool {
         if let Some(approval_data) = get_approved_all_data(&env, &owner) {
-            return approval_data.spender == spender;
+            approval_data.spender == spender
         } else {
-            return false;
+            false
         }
     }
 
     fn transfer(env: Env, owner: Address, to: Address, token_id: u32) {
         owner.require_auth();
 
+        // stop here if transfers have been paused
+        if get_pause_state(&env).transfers {
+            panic_with_error!(&env, Errors::ActionPaused);
+        }
+
         // if the token_id does not exist, panic
         if !token_exists(&env, &token_id) {
             panic_with_error!(&env, Errors::NonExistentToken);
@@ -356,7 +901,8 @@ impl NonFungibleTokenInterface for KaleSaladContract {
             panic_with_error!(&env, Errors::IncorrectOwner);
         }
 
-        if get_tokens_owned(&env, &to).len() == MAXIMUM_TOKENS_PER_ADDRESS {
+        // holders already at (or grandfathered above) the maximum can't receive more
+        if get_tokens_owned(&env, &to).len() >= get_max_per_address(&env) {
             panic_with_error!(&env, Errors::MaxTokensReached);
         }
 
@@ -376,6 +922,11 @@ impl NonFungibleTokenInterface for KaleSaladContract {
     fn transfer_from(env: Env, spender: Address, owner: Address, to: Address, token_id: u32) {
         spender.require_auth();
 
+        // stop here if transfers have been paused
+        if get_pause_state(&env).transfers {
+            panic_with_error!(&env, Errors::ActionPaused);
+        }
+
         // if the token_id does not exist, panic
         if !token_exists(&env, &token_id) {
             panic_with_error!(&env, Errors::NonExistentToken);
@@ -389,7 +940,8 @@ impl NonFungibleTokenInterface for KaleSaladContract {
             panic_with_error!(&env, Errors::InsufficientApproval);
         }
 
-        if get_tokens_owned(&env, &to).len() == MAXIMUM_TOKENS_PER_ADDRESS {
+        // holders already at (or grandfathered above) the maximum can't receive more
+        if get_tokens_owned(&env, &to).len() >= get_max_per_address(&env) {
             panic_with_error!(&env, Errors::MaxTokensReached);
         }
 
@@ -407,11 +959,11 @@ impl NonFungibleTokenInterface for KaleSaladContract {
     }
 
     fn name(env: Env) -> String {
-        return get_metadata(&env).name;
+        get_metadata(&env).name
     }
 
     fn symbol(env: Env) -> String {
-        return get_metadata(&env).symbol;
+        get_metadata(&env).symbol
     }
 
     fn token_uri(env: Env, token_id: u32) -> String {
@@ -419,16 +971,38 @@ impl NonFungibleTokenInterface for KaleSaladContract {
             panic_with_error!(&env, Errors::NonExistentToken);
         }
 
+        // a token-specific URI takes precedence over the built one
+        if let Some(uri) = get_token_uri(&env, &token_id) {
+            return uri;
+        }
+
         let base_uri = get_metadata(&env).base_uri;
-        let token_id_str = u8_to_string(&env, token_id as u8);
-        let base_uri_len = base_uri.len() as usize;
-        let token_id_str_len = token_id_str.len() as usize;
-        let combined_len = base_uri_len + token_id_str_len;
+        let suffix = get_uri_suffix(&env);
 
-        let mut slice: [u8; 70] = [0; 70];
-        base_uri.copy_into_slice(&mut slice[..base_uri_len]);
-        token_id_str.copy_into_slice(&mut slice[base_uri_len..combined_len]);
+        build_token_uri::<MAXIMUM_URI_LENGTH>(&env, &base_uri, token_id, &suffix)
+    }
+}
+
+#[contractimpl]
+impl NonFungibleTokenEnumerable for KaleSaladContract {
+    fn total_supply(env: Env) -> u32 {
+        get_supply(&env)
+    }
+
+    fn tokens_of_owner(env: Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+        let tokens_owned = get_tokens_owned(&env, &owner);
+
+        // nothing to list if we're starting past the end
+        if start >= tokens_owned.len() {
+            return Vec::new(&env);
+        }
+
+        let end = start.saturating_add(limit).min(tokens_owned.len());
+        tokens_owned.slice(start..end)
+    }
 
-        return String::from_bytes(&env, &slice[..combined_len]);
+    fn token_by_index(env: Env, index: u32) -> u32 {
+        get_token_by_index(&env, &index)
+            .unwrap_or_else(|| panic_with_error!(&env, Errors::IndexOutOfBounds))
     }
 }
@@ -1,9 +1,11 @@
 use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
 
 use crate::{
-    constants::{DAY_OF_LEDGERS, MAXIMUM_TOKENS_TO_BE_MINTED, WEEK_OF_LEDGERS},
+    constants::{DAY_OF_LEDGERS, DEFAULT_MAX_PER_ADDRESS, DEFAULT_MAX_SUPPLY, WEEK_OF_LEDGERS},
     errors::Errors,
-    types::{ApprovedData, Metadata, Storage},
+    types::{
+        ApprovedData, Metadata, PauseState, PendingAdmin, SaladRecipe, Storage, VegetableLimits,
+    },
 };
 
 pub fn set_metadata(env: &Env, name: String, symbol: String, uri: String) {
@@ -22,6 +24,29 @@ pub fn get_metadata(env: &Env) -> Metadata {
         .unwrap_or_else(|| panic_with_error!(env, Errors::UnsetMetadata))
 }
 
+pub fn set_uri_suffix(env: &Env, suffix: &String) {
+    env.storage().instance().set(&Storage::UriSuffix, suffix);
+}
+
+pub fn get_uri_suffix(env: &Env) -> String {
+    env.storage()
+        .instance()
+        .get(&Storage::UriSuffix)
+        .unwrap_or_else(|| String::from_str(env, ""))
+}
+
+pub fn set_pause_state(env: &Env, state: &PauseState) {
+    env.storage().instance().set(&Storage::Paused, state);
+}
+
+/// Falls back to nothing being paused if no state has been set
+pub fn get_pause_state(env: &Env) -> PauseState {
+    env.storage()
+        .instance()
+        .get(&Storage::Paused)
+        .unwrap_or_default()
+}
+
 pub fn set_supply(env: &Env, supply: &u32) {
     env.storage().instance().set(&Storage::Supply, supply);
 }
@@ -34,9 +59,11 @@ pub fn get_supply(env: &Env) -> u32 {
 }
 
 pub fn decrement_supply(env: &Env) {
-    env.storage().instance().update(&Storage::Supply, |s| {
-        (s.unwrap_or_default() as u32).checked_sub(1).unwrap()
-    });
+    env.storage()
+        .instance()
+        .update(&Storage::Supply, |s: Option<u32>| {
+            s.unwrap_or_default().checked_sub(1).unwrap()
+        });
 }
 
 pub fn set_mint_index(env: &Env, index: &u32) {
@@ -50,6 +77,50 @@ pub fn get_mint_index(env: &Env) -> u32 {
         .unwrap_or_default()
 }
 
+/// Places `token_id` at `index` in the list of all tokens, keeping track of
+/// the index from the token's side as well
+pub fn set_token_by_index(env: &Env, index: &u32, token_id: &u32) {
+    env.storage()
+        .persistent()
+        .set(&Storage::TokenByIndex(*index), token_id);
+    env.storage()
+        .persistent()
+        .set(&Storage::TokenIndex(*token_id), index);
+
+    extend_token_by_index_ttl(env, index);
+    extend_token_index_ttl(env, token_id);
+}
+
+pub fn get_token_by_index(env: &Env, index: &u32) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&Storage::TokenByIndex(*index))
+}
+
+/// Takes `token_id` out of the list of all tokens, moving the token at
+/// `last_index` into its place so the list has no gaps
+fn remove_token_by_index(env: &Env, token_id: &u32, last_index: u32) {
+    let index: Option<u32> = env
+        .storage()
+        .persistent()
+        .get(&Storage::TokenIndex(*token_id));
+    let Some(index) = index else {
+        return;
+    };
+
+    if index != last_index {
+        let last_token = get_token_by_index(env, &last_index).unwrap();
+        set_token_by_index(env, &index, &last_token);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&Storage::TokenByIndex(last_index));
+    env.storage()
+        .persistent()
+        .remove(&Storage::TokenIndex(*token_id));
+}
+
 pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&Storage::Admin, admin);
 }
@@ -58,9 +129,57 @@ pub fn get_admin(env: &Env) -> Address {
     env.storage().instance().get(&Storage::Admin).unwrap()
 }
 
+/// Kept in temporary storage until the proposal expires
+pub fn set_pending_admin(env: &Env, new_admin: &Address, expiration_ledger: &u32) {
+    let pending_admin = PendingAdmin {
+        address: new_admin.clone(),
+        expiration_ledger: *expiration_ledger,
+    };
+
+    env.storage()
+        .temporary()
+        .set(&Storage::PendingAdmin, &pending_admin);
+    extend_pending_admin_ttl(env, expiration_ledger);
+}
+
+/// Treats a proposal whose expiration ledger has passed as if there were none
+pub fn get_pending_admin(env: &Env) -> Option<PendingAdmin> {
+    env.storage()
+        .temporary()
+        .get::<_, PendingAdmin>(&Storage::PendingAdmin)
+        .filter(|pending| pending.expiration_ledger >= env.ledger().sequence())
+}
+
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().temporary().remove(&Storage::PendingAdmin);
+}
+
+pub fn set_max_supply(env: &Env, maximum: &u32) {
+    env.storage().instance().set(&Storage::MaxSupply, maximum);
+}
+
+pub fn get_max_supply(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&Storage::MaxSupply)
+        .unwrap_or(DEFAULT_MAX_SUPPLY)
+}
+
+pub fn set_max_per_address(env: &Env, maximum: &u32) {
+    env.storage()
+        .instance()
+        .set(&Storage::MaxPerAddress, maximum);
+}
+
+pub fn get_max_per_address(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&Storage::MaxPerAddress)
+        .unwrap_or(DEFAULT_MAX_PER_ADDRESS)
+}
+
 pub fn token_exists(env: &Env, token_id: &u32) -> bool {
-    token_id < &MAXIMUM_TOKENS_TO_BE_MINTED
-        && env.storage().persistent().has(&Storage::Owner(*token_id))
+    token_id < &get_mint_index(env) && env.storage().persistent().has(&Storage::Owner(*token_id))
 }
 
 pub fn set_tokens_owned(env: &Env, owner: &Address, tokens_owned: &Vec<u32>) {
@@ -75,7 +194,7 @@ pub fn get_tokens_owned(env: &Env, owner: &Address) -> Vec<u32> {
     env.storage()
         .persistent()
         .get(&Storage::Balance(owner.clone()))
-        .unwrap_or_else(|| Vec::new(&env))
+        .unwrap_or_else(|| Vec::new(env))
 }
 
 pub fn clear_tokens_owned(env: &Env, owner: &Address) {
@@ -96,7 +215,7 @@ pub fn spend_token(env: &Env, owner: &Address, token_id: &u32) {
 
     balance.remove(balance.first_index_of(token_id).unwrap());
 
-    if balance.len() > 0 {
+    if !balance.is_empty() {
         // owner still has other tokens, save to storage
         set_tokens_owned(env, owner, &balance);
     } else {
@@ -120,7 +239,7 @@ pub fn add_token(env: &Env, owner: &Address, token_id: &u32) {
 pub fn set_token_owner(env: &Env, token_id: &u32, owner: &Address) {
     env.storage()
         .persistent()
-        .set(&Storage::Owner(token_id.clone()), owner);
+        .set(&Storage::Owner(*token_id), owner);
 
     extend_owner_ttl(env, token_id);
 }
@@ -128,7 +247,7 @@ pub fn set_token_owner(env: &Env, token_id: &u32, owner: &Address) {
 pub fn get_token_owner(env: &Env, token_id: &u32) -> Address {
     env.storage()
         .persistent()
-        .get(&Storage::Owner(token_id.clone()))
+        .get(&Storage::Owner(*token_id))
         .unwrap()
 }
 
@@ -136,10 +255,56 @@ pub fn is_token_owner(env: &Env, owner: &Address, token_id: &u32) -> bool {
     get_token_owner(env, token_id) == owner.clone()
 }
 
+/// Removes a token's storage entries. The supply must not have been
+/// decremented yet, as the last token in the list of all tokens is found from
+/// it
 pub fn burn_token(env: &Env, token_id: &u32) {
+    remove_token_by_index(env, token_id, get_supply(env) - 1);
+
+    env.storage()
+        .persistent()
+        .remove(&Storage::Owner(*token_id));
+    env.storage()
+        .persistent()
+        .remove(&Storage::Recipe(*token_id));
+    env.storage()
+        .persistent()
+        .remove(&Storage::TokenUri(*token_id));
+}
+
+pub fn set_token_uri(env: &Env, token_id: &u32, uri: &String) {
+    env.storage()
+        .persistent()
+        .set(&Storage::TokenUri(*token_id), uri);
+
+    extend_token_uri_ttl(env, token_id);
+}
+
+pub fn get_token_uri(env: &Env, token_id: &u32) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&Storage::TokenUri(*token_id))
+}
+
+pub fn clear_token_uri(env: &Env, token_id: &u32) {
     env.storage()
         .persistent()
-        .remove(&Storage::Owner(token_id.clone()));
+        .remove(&Storage::TokenUri(*token_id));
+}
+
+pub fn set_salad_recipe(env: &Env, token_id: &u32, recipe: &SaladRecipe) {
+    env.storage()
+        .persistent()
+        .set(&Storage::Recipe(*token_id), recipe);
+
+    extend_recipe_ttl(env, token_id);
+}
+
+pub fn get_salad_recipe(env: &Env, token_id: &u32) -> SaladRecipe {
+    env.storage()
+        .persistent()
+        .get(&Storage::Recipe(*token_id))
+        .unwrap()
 }
 
 pub fn set_vegetables(env: &Env, vegetables: &Vec<Address>) {
@@ -165,11 +330,42 @@ pub fn get_payment_per_nft(env: &Env) -> i128 {
         .unwrap()
 }
 
+pub fn set_custom_price_floor(env: &Env, floor: &i128) {
+    env.storage()
+        .instance()
+        .set(&Storage::CustomPriceFloor, floor);
+}
+
+/// Falls back to the regular salad price if no floor has been configured
+pub fn get_custom_price_floor(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&Storage::CustomPriceFloor)
+        .unwrap_or_else(|| get_payment_per_nft(env))
+}
+
+pub fn set_vegetable_limits(env: &Env, vegetable: &Address, limits: &VegetableLimits) {
+    env.storage()
+        .instance()
+        .set(&Storage::Limits(vegetable.clone()), limits);
+}
+
+/// Falls back to no limits at all if none have been configured
+pub fn get_vegetable_limits(env: &Env, vegetable: &Address) -> VegetableLimits {
+    env.storage()
+        .instance()
+        .get(&Storage::Limits(vegetable.clone()))
+        .unwrap_or(VegetableLimits {
+            minimum: 0,
+            maximum: i128::MAX,
+        })
+}
+
 pub fn set_approved_data(env: &Env, spender: &Address, token_id: &u32, expiration_ledger: &u32) {
-    let key = Storage::Approved(token_id.clone());
+    let key = Storage::Approved(*token_id);
     let approved_data = ApprovedData {
         spender: spender.clone(),
-        expiration_ledger: expiration_ledger.clone(),
+        expiration_ledger: *expiration_ledger,
     };
 
     env.storage().temporary().set(&key, &approved_data);
@@ -205,9 +401,7 @@ pub fn clear_approved_data(env: &Env, token_id: &u32) {
     let key = &Storage::Approved(*token_id);
 
     if env.storage().temporary().has(key) {
-        env.storage()
-            .temporary()
-            .remove(key);
+        env.storage().temporary().remove(key);
     }
 }
 
@@ -220,7 +414,7 @@ pub fn set_approved_all_data(
     let key = Storage::ApprovedAll(owner.clone());
     let approved_data = ApprovedData {
         spender: spender.clone(),
-        expiration_ledger: expiration_ledger.clone(),
+        expiration_ledger: *expiration_ledger,
     };
 
     env.storage().temporary().set(&key, &approved_data);
@@ -247,23 +441,31 @@ pub fn clear_approved_all_data(env: &Env, owner: &Address) {
     let key = &Storage::ApprovedAll(owner.clone());
 
     if env.storage().temporary().has(key) {
-        env.storage()
-            .temporary()
-            .remove(key);
+        env.storage().temporary().remove(key);
     }
 }
 
 pub fn spender_is_approved(env: &Env, owner: &Address, spender: &Address, token_id: &u32) -> bool {
-    let approved_all = match get_approved_all_data(&env, &owner) {
+    let approved_all = match get_approved_all_data(env, owner) {
         Some(data) => data.spender == spender.clone(),
         _ => false,
     };
-    let approved = match get_approved_data(&env, &token_id) {
+    let approved = match get_approved_data(env, token_id) {
         Some(data) => data.spender == spender.clone(),
         _ => false,
     };
 
-    return approved || approved_all;
+    approved || approved_all
+}
+
+pub fn extend_pending_admin_ttl(env: &Env, expiration_ledger: &u32) {
+    let live_for = expiration_ledger
+        .checked_sub(env.ledger().sequence())
+        .unwrap();
+
+    env.storage()
+        .temporary()
+        .extend_ttl(&Storage::PendingAdmin, live_for, live_for);
 }
 
 pub fn extend_instance_ttl(env: &Env) {
@@ -274,6 +476,26 @@ pub fn extend_instance_ttl(env: &Env) {
         .extend_ttl(max_ttl - WEEK_OF_LEDGERS, max_ttl);
 }
 
+pub fn extend_token_by_index_ttl(env: &Env, index: &u32) {
+    let max_ttl = env.storage().max_ttl();
+
+    env.storage().persistent().extend_ttl(
+        &Storage::TokenByIndex(*index),
+        max_ttl - DAY_OF_LEDGERS,
+        max_ttl,
+    );
+}
+
+pub fn extend_token_index_ttl(env: &Env, token_id: &u32) {
+    let max_ttl = env.storage().max_ttl();
+
+    env.storage().persistent().extend_ttl(
+        &Storage::TokenIndex(*token_id),
+        max_ttl - DAY_OF_LEDGERS,
+        max_ttl,
+    );
+}
+
 pub fn extend_balance_ttl(env: &Env, owner: &Address) {
     let max_ttl = env.storage().max_ttl();
 
@@ -288,7 +510,27 @@ pub fn extend_owner_ttl(env: &Env, token_id: &u32) {
     let max_ttl = env.storage().max_ttl();
 
     env.storage().persistent().extend_ttl(
-        &Storage::Owner(token_id.clone()),
+        &Storage::Owner(*token_id),
+        max_ttl - DAY_OF_LEDGERS,
+        max_ttl,
+    );
+}
+
+pub fn extend_recipe_ttl(env: &Env, token_id: &u32) {
+    let max_ttl = env.storage().max_ttl();
+
+    env.storage().persistent().extend_ttl(
+        &Storage::Recipe(*token_id),
+        max_ttl - DAY_OF_LEDGERS,
+        max_ttl,
+    );
+}
+
+pub fn extend_token_uri_ttl(env: &Env, token_id: &u32) {
+    let max_ttl = env.storage().max_ttl();
+
+    env.storage().persistent().extend_ttl(
+        &Storage::TokenUri(*token_id),
         max_ttl - DAY_OF_LEDGERS,
         max_ttl,
     );
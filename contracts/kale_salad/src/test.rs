@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::types::{ApprovedData, Metadata, Storage};
+use crate::types::{ApprovedData, Metadata, Storage, SupplyLimits};
 use crate::KaleSaladContract;
 // use crate::storage::Storage;
 use soroban_sdk::{
@@ -27,5 +27,12 @@ mod transfer;
 mod transfer_from;
 
 // test my own functionality
+mod admin;
 mod constructor;
+mod enumerable;
+mod limits;
 mod mint;
+mod mint_custom;
+mod pause;
+mod recipe;
+mod utils;
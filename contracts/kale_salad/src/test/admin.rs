@@ -0,0 +1,179 @@
+use super::*;
+
+use crate::types::PendingAdmin;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    IntoVal, Symbol,
+};
+
+#[test]
+fn test_admin_handover() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+    let new_admin = Address::generate(&env);
+    let expiration_ledger = env.ledger().sequence() + 100;
+
+    kale_salad_client.propose_admin(&new_admin, &expiration_ledger);
+    assert_eq!(
+        kale_salad_client.pending_admin(),
+        Some(PendingAdmin {
+            address: new_admin.clone(),
+            expiration_ledger,
+        })
+    );
+    // nothing changes until the proposal is accepted
+    assert_eq!(kale_salad_client.admin(), fixture.admin);
+
+    kale_salad_client.accept_admin();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                kale_salad_client.address.clone(),
+                (Symbol::new(&env, "accept_admin"), fixture.admin.clone()).into_val(&env),
+                new_admin.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(
+        env.auths()[0].0,
+        new_admin,
+        "the new admin must authorize accepting"
+    );
+
+    assert_eq!(kale_salad_client.admin(), new_admin);
+    assert_eq!(kale_salad_client.pending_admin(), None);
+}
+
+#[test]
+fn test_propose_admin_event() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+    let new_admin = Address::generate(&env);
+    let expiration_ledger = env.ledger().sequence() + 100;
+
+    kale_salad_client.propose_admin(&new_admin, &expiration_ledger);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                kale_salad_client.address.clone(),
+                (Symbol::new(&env, "propose_admin"), fixture.admin).into_val(&env),
+                (new_admin, expiration_ledger).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_cancel_admin_proposal() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+    let new_admin = Address::generate(&env);
+
+    kale_salad_client.propose_admin(&new_admin, &(env.ledger().sequence() + 100));
+    kale_salad_client.cancel_admin_proposal();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                kale_salad_client.address.clone(),
+                (Symbol::new(&env, "cancel_admin"), fixture.admin.clone()).into_val(&env),
+                new_admin.into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(kale_salad_client.pending_admin(), None);
+    assert_eq!(kale_salad_client.admin(), fixture.admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #501)")]
+fn test_cannot_accept_cancelled_proposal() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.propose_admin(&Address::generate(&env), &(env.ledger().sequence() + 100));
+    kale_salad_client.cancel_admin_proposal();
+    kale_salad_client.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #501)")]
+fn test_cannot_accept_expired_proposal() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+    let expiration_ledger = env.ledger().sequence() + 100;
+
+    kale_salad_client.propose_admin(&Address::generate(&env), &expiration_ledger);
+
+    env.ledger().set_sequence_number(expiration_ledger + 1);
+    assert_eq!(kale_salad_client.pending_admin(), None);
+
+    kale_salad_client.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #501)")]
+fn test_cannot_accept_without_proposal() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #501)")]
+fn test_cannot_cancel_without_proposal() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.cancel_admin_proposal();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn test_cannot_propose_with_expired_ledger() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+
+    env.ledger().set_sequence_number(100);
+    kale_salad_client.propose_admin(&Address::generate(&env), &99);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn test_cannot_propose_past_max_ttl() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+    let expiration_ledger = env.ledger().sequence() + env.ledger().get().max_entry_ttl;
+
+    kale_salad_client.propose_admin(&Address::generate(&env), &expiration_ledger);
+}
+
+#[test]
+fn test_new_proposal_replaces_old() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+    let first_admin = Address::generate(&env);
+    let second_admin = Address::generate(&env);
+    let expiration_ledger = env.ledger().sequence() + 100;
+
+    kale_salad_client.propose_admin(&first_admin, &expiration_ledger);
+    kale_salad_client.propose_admin(&second_admin, &expiration_ledger);
+    kale_salad_client.accept_admin();
+
+    assert_eq!(kale_salad_client.admin(), second_admin);
+}
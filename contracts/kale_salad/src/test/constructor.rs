@@ -55,6 +55,7 @@ fn test_cannot_construct_with_too_few_vegetables() {
             String::from_str(&env, IPFS_URI),
             vec![&env, broc.address, cabb.address, kohl.address],
             (10 * 10_000_000) as i128,
+            None::<SupplyLimits>,
         ),
     );
 }
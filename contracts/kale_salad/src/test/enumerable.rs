@@ -0,0 +1,161 @@
+use super::*;
+
+use soroban_nft_interface::NonFungibleTokenEnumerableClient;
+use soroban_sdk::testutils::Address as _;
+
+#[test]
+fn test_total_supply() {
+    let fixture = TestFixture::create();
+
+    let owner1 = fixture.owners.get(0).unwrap();
+    let owner2 = fixture.owners.get(1).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    assert_eq!(kale_salad_client.total_supply(), 0);
+
+    kale_salad_client.mint_salad(&owner1, &Some(3));
+    kale_salad_client.mint_salad(&owner2, &Some(2));
+    assert_eq!(kale_salad_client.total_supply(), 5);
+
+    kale_salad_client.burn(&owner1, &1);
+    assert_eq!(kale_salad_client.total_supply(), 4);
+}
+
+#[test]
+fn test_tokens_of_owner() {
+    let fixture = TestFixture::create();
+
+    let owner1 = fixture.owners.get(0).unwrap();
+    let owner2 = fixture.owners.get(1).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner1, &Some(2));
+    kale_salad_client.mint_salad(&owner2, &Some(1));
+    kale_salad_client.mint_salad(&owner1, &Some(3));
+
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner1, &0, &10),
+        vec![&fixture.env, 0, 1, 3, 4, 5]
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner2, &0, &10),
+        vec![&fixture.env, 2]
+    );
+
+    // follow the tokens around when they're transferred
+    kale_salad_client.transfer(&owner1, &owner2, &3);
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner1, &0, &10),
+        vec![&fixture.env, 0, 1, 4, 5]
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner2, &0, &10),
+        vec![&fixture.env, 2, 3]
+    );
+}
+
+#[test]
+fn test_tokens_of_owner_pagination() {
+    let fixture = TestFixture::create();
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(5));
+
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner, &0, &2),
+        vec![&fixture.env, 0, 1]
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner, &2, &2),
+        vec![&fixture.env, 2, 3]
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner, &4, &2),
+        vec![&fixture.env, 4]
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner, &5, &2),
+        Vec::<u32>::new(&fixture.env)
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner, &0, &u32::MAX),
+        vec![&fixture.env, 0, 1, 2, 3, 4]
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&owner, &0, &0),
+        Vec::<u32>::new(&fixture.env)
+    );
+    assert_eq!(
+        kale_salad_client.tokens_of_owner(&Address::generate(&fixture.env), &0, &10),
+        Vec::<u32>::new(&fixture.env)
+    );
+}
+
+#[test]
+fn test_token_by_index() {
+    let fixture = TestFixture::create();
+
+    let owner1 = fixture.owners.get(0).unwrap();
+    let owner2 = fixture.owners.get(1).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner1, &Some(3));
+    kale_salad_client.mint_salad(&owner2, &Some(2));
+
+    for index in 0..5 {
+        assert_eq!(kale_salad_client.token_by_index(&index), index);
+    }
+
+    // burned tokens drop out of the list, and the last one fills the gap
+    kale_salad_client.burn(&owner1, &1);
+    assert_eq!(kale_salad_client.token_by_index(&0), 0);
+    assert_eq!(kale_salad_client.token_by_index(&1), 4);
+    assert_eq!(kale_salad_client.token_by_index(&2), 2);
+    assert_eq!(kale_salad_client.token_by_index(&3), 3);
+
+    // burning the last token leaves the rest where they are
+    kale_salad_client.burn(&owner2, &3);
+    assert_eq!(kale_salad_client.token_by_index(&0), 0);
+    assert_eq!(kale_salad_client.token_by_index(&1), 4);
+    assert_eq!(kale_salad_client.token_by_index(&2), 2);
+
+    // and new tokens go on the end
+    kale_salad_client.mint_salad(&owner2, &Some(1));
+    assert_eq!(kale_salad_client.total_supply(), 4);
+    assert_eq!(kale_salad_client.token_by_index(&3), 5);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #305)")]
+fn test_cannot_get_token_past_total_supply() {
+    let fixture = TestFixture::create();
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(2));
+    kale_salad_client.burn(&owner, &0);
+
+    kale_salad_client.token_by_index(&1);
+}
+
+#[test]
+fn test_enumerable_client() {
+    let fixture = TestFixture::create();
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let enumerable_client =
+        NonFungibleTokenEnumerableClient::new(&fixture.env, &kale_salad_client.address);
+
+    kale_salad_client.mint_salad(&owner, &Some(2));
+
+    assert_eq!(enumerable_client.total_supply(), 2);
+    assert_eq!(
+        enumerable_client.tokens_of_owner(&owner, &0, &10),
+        vec![&fixture.env, 0, 1]
+    );
+    assert_eq!(enumerable_client.token_by_index(&1), 1);
+}
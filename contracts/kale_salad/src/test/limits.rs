@@ -0,0 +1,199 @@
+use super::*;
+
+use crate::KaleSaladContractClient;
+use soroban_sdk::{testutils::Events, IntoVal, Symbol};
+
+#[test]
+fn test_default_limits() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+
+    assert_eq!(kale_salad_client.max_supply(), 250);
+    assert_eq!(kale_salad_client.max_per_address(), 5);
+
+    // make sure the storage entries are there
+    env.as_contract(&kale_salad_client.address, || {
+        assert_eq!(
+            env.storage()
+                .instance()
+                .get::<_, u32>(&Storage::MaxSupply)
+                .unwrap(),
+            250
+        );
+        assert_eq!(
+            env.storage()
+                .instance()
+                .get::<_, u32>(&Storage::MaxPerAddress)
+                .unwrap(),
+            5
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_constructor_limits() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let owner = fixture.owners.get(0).unwrap();
+    let [(broc, _), (cabb, _), (kohl, _), (brsp, _)] = fixture.vegetables;
+
+    let kale_salad_address = env.register(
+        KaleSaladContract,
+        (
+            fixture.admin,
+            String::from_str(&env, NFT_NAME),
+            String::from_str(&env, NFT_SYMBOL),
+            String::from_str(&env, IPFS_URI),
+            vec![&env, broc.address, cabb.address, kohl.address, brsp.address],
+            (10 * 10_000_000) as i128,
+            Some(SupplyLimits {
+                max_supply: 10,
+                max_per_address: 2,
+            }),
+        ),
+    );
+    let kale_salad_client = KaleSaladContractClient::new(&env, &kale_salad_address);
+
+    assert_eq!(kale_salad_client.max_supply(), 10);
+    assert_eq!(kale_salad_client.max_per_address(), 2);
+
+    kale_salad_client.mint_salad(&owner, &Some(3));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #201)")]
+fn test_cannot_mint_past_lowered_max_supply() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_max_supply(&6);
+    assert_eq!(
+        fixture.env.events().all(),
+        vec![
+            &fixture.env,
+            (
+                kale_salad_client.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "max_supply"),
+                    fixture.admin.clone()
+                )
+                    .into_val(&fixture.env),
+                6u32.into_val(&fixture.env),
+            ),
+        ]
+    );
+    assert_eq!(kale_salad_client.max_supply(), 6);
+
+    kale_salad_client.mint_salad(&fixture.owners.get(0).unwrap(), &Some(5));
+    kale_salad_client.mint_salad(&fixture.owners.get(1).unwrap(), &Some(1));
+    kale_salad_client.mint_salad(&fixture.owners.get(2).unwrap(), &Some(1));
+}
+
+#[test]
+fn test_can_lower_max_supply_to_minted() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&fixture.owners.get(0).unwrap(), &Some(5));
+    kale_salad_client.set_max_supply(&5);
+    assert_eq!(kale_salad_client.max_supply(), 5);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn test_cannot_lower_max_supply_below_minted() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&fixture.owners.get(0).unwrap(), &Some(5));
+    kale_salad_client.mint_salad(&fixture.owners.get(1).unwrap(), &Some(1));
+    // burned tokens still count as having been minted
+    kale_salad_client.burn(&fixture.owners.get(1).unwrap(), &5);
+
+    kale_salad_client.set_max_supply(&5);
+}
+
+#[test]
+fn test_can_mint_more_with_raised_max_per_address() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_max_per_address(&10);
+    assert_eq!(
+        fixture.env.events().all(),
+        vec![
+            &fixture.env,
+            (
+                kale_salad_client.address.clone(),
+                (
+                    Symbol::new(&fixture.env, "max_per_address"),
+                    fixture.admin.clone()
+                )
+                    .into_val(&fixture.env),
+                10u32.into_val(&fixture.env),
+            ),
+        ]
+    );
+    assert_eq!(kale_salad_client.max_per_address(), 10);
+
+    kale_salad_client.mint_salad(&owner, &Some(5));
+    kale_salad_client.mint_salad(&owner, &Some(5));
+    assert_eq!(kale_salad_client.balance(&owner), 10);
+}
+
+#[test]
+fn test_lowered_max_per_address_grandfathers_holders() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let receiver = fixture.owners.get(1).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(5));
+    kale_salad_client.set_max_per_address(&2);
+
+    // the existing holder keeps everything, and can still send them along
+    assert_eq!(kale_salad_client.balance(&owner), 5);
+    kale_salad_client.transfer(&owner, &receiver, &0);
+    assert_eq!(kale_salad_client.balance(&owner), 4);
+    assert_eq!(kale_salad_client.balance(&receiver), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_grandfathered_holder_cannot_mint_more() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(3));
+    kale_salad_client.set_max_per_address(&2);
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_grandfathered_holder_cannot_receive_more() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let receiver = fixture.owners.get(1).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    kale_salad_client.mint_salad(&receiver, &Some(3));
+    kale_salad_client.set_max_per_address(&2);
+
+    kale_salad_client.transfer(&owner, &receiver, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn test_cannot_set_zero_max_per_address() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_max_per_address(&0);
+}
@@ -24,3 +24,114 @@ fn test_metadata_interface() {
         String::from_str(&env, TOKEN_4_URI,)
     );
 }
+
+#[test]
+fn test_token_uri_with_suffix() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let kale_salad_client = fixture.kale_salad_client;
+    kale_salad_client.mint_salad(&fixture.owners.get(0).unwrap(), &Some(5));
+
+    assert_eq!(kale_salad_client.uri_suffix(), String::from_str(&env, ""));
+    kale_salad_client.set_uri_suffix(&String::from_str(&env, ".json"));
+    assert_eq!(
+        kale_salad_client.uri_suffix(),
+        String::from_str(&env, ".json")
+    );
+
+    assert_eq!(
+        kale_salad_client.token_uri(&4),
+        String::from_str(
+            &env,
+            "ipfs://bafybeifmrpa7cpep7j6thdgs4dsteev7lvfwwvxuywofarnghdwevizdsa/4.json"
+        )
+    );
+}
+
+#[test]
+fn test_token_uri_with_long_base_uri() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let kale_salad_client = fixture.kale_salad_client;
+    kale_salad_client.mint_salad(&fixture.owners.get(0).unwrap(), &Some(5));
+
+    // longer than the 70 bytes the contract used to be able to handle
+    let base_uri = "https://kalefail.elliotfriend.com/api/salads/metadata/bafybeifmrpa7cpep7j6thdgs4dsteev7lvfwwvxuywofarnghdwevizdsa/";
+    kale_salad_client.set_base_uri(&String::from_str(&env, base_uri));
+
+    assert_eq!(
+        kale_salad_client.token_uri(&4),
+        String::from_str(
+            &env,
+            "https://kalefail.elliotfriend.com/api/salads/metadata/bafybeifmrpa7cpep7j6thdgs4dsteev7lvfwwvxuywofarnghdwevizdsa/4"
+        )
+    );
+}
+
+#[test]
+fn test_token_uri_override() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let kale_salad_client = fixture.kale_salad_client;
+    kale_salad_client.mint_salad(&fixture.owners.get(0).unwrap(), &Some(5));
+
+    let special_uri = String::from_str(
+        &env,
+        "ipfs://bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku",
+    );
+    kale_salad_client.set_token_uri(&4, &special_uri);
+
+    assert_eq!(kale_salad_client.token_uri(&4), special_uri);
+    // other tokens are unaffected
+    assert_eq!(
+        kale_salad_client.token_uri(&3),
+        String::from_str(
+            &env,
+            "ipfs://bafybeifmrpa7cpep7j6thdgs4dsteev7lvfwwvxuywofarnghdwevizdsa/3"
+        )
+    );
+
+    kale_salad_client.remove_token_uri(&4);
+    assert_eq!(
+        kale_salad_client.token_uri(&4),
+        String::from_str(&env, TOKEN_4_URI)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_cannot_set_base_uri_too_long() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let kale_salad_client = fixture.kale_salad_client;
+
+    let slice = [b'a'; 250];
+    kale_salad_client.set_base_uri(&String::from_bytes(&env, &slice));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_cannot_set_uri_suffix_too_long() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let kale_salad_client = fixture.kale_salad_client;
+
+    let slice = [b'a'; 200];
+    kale_salad_client.set_uri_suffix(&String::from_bytes(&env, &slice));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn test_cannot_set_token_uri_of_unminted_token() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_token_uri(&0, &String::from_str(&env, "ipfs://nope"));
+}
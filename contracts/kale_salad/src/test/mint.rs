@@ -1,7 +1,8 @@
 use super::*;
 
+use crate::KaleSaladContractClient;
 use soroban_sdk::testutils::Address as _;
-use test_fixture::{create_owners_vec, mint_vegetables_to_owners};
+use test_fixture::{create_owners_vec, create_stellar_token, mint_vegetables_to_owners};
 
 #[test]
 fn test_can_mint() {
@@ -107,3 +108,45 @@ fn test_cannot_exceed_maximum_tokens_to_be_minted() {
     // this should fail, even though we're willing to pay twice the price!
     kale_salad_client.mint_salad(&late_owner, &Some(1));
 }
+
+#[test]
+fn test_can_mint_with_more_than_four_vegetables() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let [(broc, broc_client), (cabb, cabb_client), (kohl, kohl_client), (brsp, brsp_client)] =
+        fixture.vegetables;
+    let (pumpkin, pumpkin_sac, pumpkin_client) = create_stellar_token(&env, &fixture.admin);
+    pumpkin_sac.mint(&owner, &(1000 * 10_000_000));
+
+    let kale_salad_address = env.register(
+        KaleSaladContract,
+        (
+            fixture.admin,
+            String::from_str(&env, NFT_NAME),
+            String::from_str(&env, NFT_SYMBOL),
+            String::from_str(&env, IPFS_URI),
+            vec![
+                &env,
+                broc.address,
+                cabb.address,
+                kohl.address,
+                brsp.address,
+                pumpkin,
+            ],
+            (10 * 10_000_000) as i128,
+            None::<SupplyLimits>,
+        ),
+    );
+    let kale_salad_client = KaleSaladContractClient::new(&env, &kale_salad_address);
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+
+    // each vegetable pays its own share, rather than a quarter of the price
+    assert_eq!(broc_client.balance(&owner), 990 * 10_000_000);
+    assert_eq!(cabb_client.balance(&owner), 990 * 10_000_000);
+    assert_eq!(kohl_client.balance(&owner), 990 * 10_000_000);
+    assert_eq!(brsp_client.balance(&owner), 990 * 10_000_000);
+    assert_eq!(pumpkin_client.balance(&owner), 990 * 10_000_000);
+}
@@ -0,0 +1,268 @@
+use super::*;
+
+use test_fixture::create_stellar_token;
+
+use crate::types::{SaladRecipe, VegetableLimits};
+use soroban_sdk::{map, testutils::Address as _};
+
+#[test]
+fn test_can_mint_custom_salad() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, broc_client), (cabb, cabb_client), (_, kohl_client), (_, brsp_client)] =
+        fixture.vegetables;
+
+    // all broccoli and cabbage, no kohlrabi or brussels sprouts
+    kale_salad_client.mint_custom_salad(
+        &owner,
+        &map![
+            &env,
+            (broc.address.clone(), 25 * 10_000_000),
+            (cabb.address.clone(), 15 * 10_000_000),
+        ],
+    );
+
+    assert_eq!(broc_client.balance(&owner), 975 * 10_000_000);
+    assert_eq!(cabb_client.balance(&owner), 985 * 10_000_000);
+    assert_eq!(kohl_client.balance(&owner), 1000 * 10_000_000);
+    assert_eq!(brsp_client.balance(&owner), 1000 * 10_000_000);
+
+    assert_eq!(kale_salad_client.balance(&owner), 1);
+    assert_eq!(kale_salad_client.owner_of(&0), owner);
+    assert_eq!(
+        kale_salad_client.salad_recipe(&0),
+        SaladRecipe {
+            ingredients: map![
+                &env,
+                (broc.address.clone(), 25 * 10_000_000),
+                (cabb.address.clone(), 15 * 10_000_000),
+            ],
+            mint_ledger: env.ledger().sequence(),
+        }
+    );
+}
+
+#[test]
+fn test_can_mint_custom_salad_with_lower_price_floor() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, broc_client), ..] = fixture.vegetables;
+
+    // the floor defaults to the regular salad price
+    assert_eq!(kale_salad_client.custom_price_floor(), 40 * 10_000_000);
+    kale_salad_client.set_custom_price_floor(&(10 * 10_000_000));
+    assert_eq!(kale_salad_client.custom_price_floor(), 10 * 10_000_000);
+
+    kale_salad_client
+        .mint_custom_salad(&owner, &map![&env, (broc.address.clone(), 10 * 10_000_000)]);
+
+    assert_eq!(broc_client.balance(&owner), 990 * 10_000_000);
+    assert_eq!(kale_salad_client.balance(&owner), 1);
+}
+
+#[test]
+fn test_set_vegetable_limits() {
+    let fixture = TestFixture::create();
+
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), (cabb, _), ..] = fixture.vegetables;
+
+    kale_salad_client.set_vegetable_limits(&broc.address, &(5 * 10_000_000), &(20 * 10_000_000));
+
+    assert_eq!(
+        kale_salad_client.vegetable_limits(&broc.address),
+        VegetableLimits {
+            minimum: 5 * 10_000_000,
+            maximum: 20 * 10_000_000,
+        }
+    );
+    // vegetables without configured limits are unbounded
+    assert_eq!(
+        kale_salad_client.vegetable_limits(&cabb.address),
+        VegetableLimits {
+            minimum: 0,
+            maximum: i128::MAX,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn test_cannot_mint_custom_salad_with_unknown_vegetable() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), ..] = fixture.vegetables;
+
+    let (pumpkin, pumpkin_sac, _) = create_stellar_token(&env, &fixture.admin);
+    pumpkin_sac.mint(&owner, &(1000 * 10_000_000));
+
+    kale_salad_client.mint_custom_salad(
+        &owner,
+        &map![
+            &env,
+            (broc.address.clone(), 20 * 10_000_000),
+            (pumpkin, 20 * 10_000_000),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_cannot_mint_custom_salad_above_maximum() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), (cabb, _), ..] = fixture.vegetables;
+
+    kale_salad_client.set_vegetable_limits(&broc.address, &0, &(20 * 10_000_000));
+
+    kale_salad_client.mint_custom_salad(
+        &owner,
+        &map![
+            &env,
+            (broc.address.clone(), 25 * 10_000_000),
+            (cabb.address.clone(), 15 * 10_000_000),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_cannot_mint_custom_salad_missing_required_vegetable() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), (cabb, _), (kohl, _), _] = fixture.vegetables;
+
+    // every salad needs at least a little kohlrabi
+    kale_salad_client.set_vegetable_limits(&kohl.address, &10_000_000, &i128::MAX);
+
+    kale_salad_client.mint_custom_salad(
+        &owner,
+        &map![
+            &env,
+            (broc.address.clone(), 25 * 10_000_000),
+            (cabb.address.clone(), 15 * 10_000_000),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_cannot_mint_custom_salad_with_negative_amount() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), (cabb, _), ..] = fixture.vegetables;
+
+    kale_salad_client.mint_custom_salad(
+        &owner,
+        &map![
+            &env,
+            (broc.address.clone(), 50 * 10_000_000),
+            (cabb.address.clone(), -10 * 10_000_000),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #107)")]
+fn test_cannot_mint_custom_salad_below_price_floor() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), (cabb, _), ..] = fixture.vegetables;
+
+    kale_salad_client.mint_custom_salad(
+        &owner,
+        &map![
+            &env,
+            (broc.address.clone(), 20 * 10_000_000),
+            (cabb.address.clone(), 10 * 10_000_000),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_cannot_exceed_maximum_tokens_per_address_with_custom_salad() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), ..] = fixture.vegetables;
+
+    kale_salad_client.mint_salad(&owner, &Some(5));
+    kale_salad_client
+        .mint_custom_salad(&owner, &map![&env, (broc.address.clone(), 40 * 10_000_000)]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn test_cannot_set_limits_for_unknown_vegetable() {
+    let fixture = TestFixture::create();
+
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_vegetable_limits(&Address::generate(&fixture.env), &0, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_cannot_set_minimum_above_maximum() {
+    let fixture = TestFixture::create();
+
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), ..] = fixture.vegetables;
+
+    kale_salad_client.set_vegetable_limits(&broc.address, &100, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_cannot_set_negative_minimum() {
+    let fixture = TestFixture::create();
+
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), ..] = fixture.vegetables;
+
+    kale_salad_client.set_vegetable_limits(&broc.address, &-10, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #108)")]
+fn test_cannot_set_zero_price_floor() {
+    let fixture = TestFixture::create();
+
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_custom_price_floor(&0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #108)")]
+fn test_cannot_set_negative_price_floor() {
+    let fixture = TestFixture::create();
+
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_custom_price_floor(&-1);
+}
@@ -0,0 +1,198 @@
+use super::*;
+
+use crate::types::PauseState;
+use soroban_sdk::{map, symbol_short, testutils::Events, IntoVal};
+
+#[test]
+fn test_nothing_paused_by_default() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    assert_eq!(kale_salad_client.pause_state(), PauseState::default());
+}
+
+#[test]
+fn test_switches_are_independent() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_transfer_paused(&true);
+    assert_eq!(
+        kale_salad_client.pause_state(),
+        PauseState {
+            minting: false,
+            transfers: true,
+            burns: false,
+        }
+    );
+
+    kale_salad_client.set_burn_paused(&true);
+    kale_salad_client.set_transfer_paused(&false);
+    assert_eq!(
+        kale_salad_client.pause_state(),
+        PauseState {
+            minting: false,
+            transfers: false,
+            burns: true,
+        }
+    );
+}
+
+#[test]
+fn test_pause_event() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_mint_paused(&true);
+
+    let expected_state = PauseState {
+        minting: true,
+        transfers: false,
+        burns: false,
+    };
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                kale_salad_client.address.clone(),
+                (symbol_short!("pause"), fixture.admin).into_val(&env),
+                expected_state.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn test_cannot_mint_while_paused() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_mint_paused(&true);
+    kale_salad_client.mint_salad(&owner, &Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn test_cannot_mint_custom_salad_while_paused() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), ..] = fixture.vegetables;
+
+    kale_salad_client.set_mint_paused(&true);
+    kale_salad_client.mint_custom_salad(&owner, &map![&env, (broc.address, 40 * 10_000_000)]);
+}
+
+#[test]
+fn test_can_mint_after_unpausing() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.set_mint_paused(&true);
+    kale_salad_client.set_mint_paused(&false);
+    kale_salad_client.mint_salad(&owner, &Some(1));
+
+    assert_eq!(kale_salad_client.balance(&owner), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn test_cannot_transfer_while_paused() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let receiver = fixture.owners.get(1).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    kale_salad_client.set_transfer_paused(&true);
+    kale_salad_client.transfer(&owner, &receiver, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn test_cannot_transfer_from_while_paused() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let owner = fixture.owners.get(0).unwrap();
+    let spender = fixture.owners.get(1).unwrap();
+    let receiver = fixture.owners.get(2).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    kale_salad_client.approve(&owner, &spender, &0, &(env.ledger().sequence() + 100));
+    kale_salad_client.set_transfer_paused(&true);
+    kale_salad_client.transfer_from(&spender, &owner, &receiver, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn test_cannot_burn_while_paused() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    kale_salad_client.set_burn_paused(&true);
+    kale_salad_client.burn(&owner, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn test_cannot_burn_from_while_paused() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+    let owner = fixture.owners.get(0).unwrap();
+    let spender = fixture.owners.get(1).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    kale_salad_client.approve(&owner, &spender, &0, &(env.ledger().sequence() + 100));
+    kale_salad_client.set_burn_paused(&true);
+    kale_salad_client.burn_from(&spender, &owner, &0);
+}
+
+#[test]
+fn test_paused_transfers_still_allow_burns() {
+    let fixture = TestFixture::create();
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    kale_salad_client.set_transfer_paused(&true);
+    kale_salad_client.burn(&owner, &0);
+
+    assert_eq!(kale_salad_client.balance(&owner), 0);
+}
+
+#[test]
+fn test_pause_all() {
+    let fixture = TestFixture::create();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.pause_all();
+    assert_eq!(
+        kale_salad_client.pause_state(),
+        PauseState {
+            minting: true,
+            transfers: true,
+            burns: true,
+        }
+    );
+
+    // each switch can be flipped back on its own
+    kale_salad_client.set_burn_paused(&false);
+    assert_eq!(
+        kale_salad_client.pause_state(),
+        PauseState {
+            minting: true,
+            transfers: true,
+            burns: false,
+        }
+    );
+}
@@ -0,0 +1,101 @@
+use super::*;
+
+use crate::types::SaladRecipe;
+use soroban_sdk::{
+    map, symbol_short,
+    testutils::{Events, Ledger},
+    IntoVal,
+};
+
+#[test]
+fn test_recipe_is_stored_on_mint() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+    let [(broc, _), (cabb, _), (kohl, _), (brsp, _)] = fixture.vegetables;
+
+    env.ledger().set_sequence_number(123);
+    kale_salad_client.mint_salad(&owner, &Some(2));
+
+    let expected_recipe = SaladRecipe {
+        ingredients: map![
+            &env,
+            (broc.address.clone(), 10 * 10_000_000),
+            (cabb.address.clone(), 10 * 10_000_000),
+            (kohl.address.clone(), 10 * 10_000_000),
+            (brsp.address.clone(), 10 * 10_000_000),
+        ],
+        mint_ledger: 123,
+    };
+
+    // each minted salad gets its own share of the payment, not the total
+    assert_eq!(kale_salad_client.salad_recipe(&0), expected_recipe);
+    assert_eq!(kale_salad_client.salad_recipe(&1), expected_recipe);
+
+    // check the storage entry
+    let stored_recipe: Option<SaladRecipe> = env.as_contract(&kale_salad_client.address, || {
+        env.storage().persistent().get(&Storage::Recipe(1))
+    });
+    assert_eq!(stored_recipe.unwrap(), expected_recipe);
+}
+
+#[test]
+fn test_recipe_is_emitted_on_mint() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    let events = env.events().all();
+    let recipe = kale_salad_client.salad_recipe(&0);
+
+    // only look at the salad's own events, not the vegetable burns
+    let mut salad_events = vec![&env];
+    for event in events.iter() {
+        if event.0 == kale_salad_client.address {
+            salad_events.push_back(event);
+        }
+    }
+    assert_eq!(
+        salad_events,
+        vec![
+            &env,
+            (
+                kale_salad_client.address.clone(),
+                (symbol_short!("mint"), fixture.admin, owner).into_val(&env),
+                (0u32, recipe).into_val(&env),
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_recipe_is_removed_on_burn() {
+    let fixture = TestFixture::create();
+    let env = fixture.env;
+
+    let owner = fixture.owners.get(0).unwrap();
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.mint_salad(&owner, &Some(1));
+    kale_salad_client.burn(&owner, &0);
+
+    let stored_recipe: Option<SaladRecipe> = env.as_contract(&kale_salad_client.address, || {
+        env.storage().persistent().get(&Storage::Recipe(0))
+    });
+    assert!(stored_recipe.is_none());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn test_cannot_get_recipe_of_unminted_token() {
+    let fixture = TestFixture::create();
+
+    let kale_salad_client = fixture.kale_salad_client;
+
+    kale_salad_client.salad_recipe(&0);
+}
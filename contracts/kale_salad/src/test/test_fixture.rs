@@ -3,27 +3,27 @@ use soroban_sdk::{testutils::Address as _, token::StellarAssetClient};
 use super::*;
 use crate::KaleSaladContractClient;
 
-fn create_stellar_token<'a>(
+pub fn create_stellar_token<'a>(
     env: &Env,
     admin: &Address,
 ) -> (Address, StellarAssetClient<'a>, token::Client<'a>) {
     let contract_address = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
-    let sac_client = StellarAssetClient::new(&env, &contract_address);
+    let sac_client = StellarAssetClient::new(env, &contract_address);
     sac_client.set_admin(admin);
-    let sep_client = token::Client::new(&env, &contract_address);
+    let sep_client = token::Client::new(env, &contract_address);
 
-    return (contract_address, sac_client, sep_client);
+    (contract_address, sac_client, sep_client)
 }
 
-pub fn create_owners_vec<'a>(env: &Env, num_owners: &u8) -> Vec<Address> {
+pub fn create_owners_vec(env: &Env, num_owners: &u8) -> Vec<Address> {
     let mut owners_vec = vec![env];
     for _ in 0..*num_owners {
         owners_vec.push_back(Address::generate(env));
     }
 
-    return owners_vec;
+    owners_vec
 }
 
 pub fn mint_vegetables_to_owners<'a>(
@@ -77,16 +77,17 @@ impl TestFixture<'_> {
                 String::from_str(&env, IPFS_URI),
                 vec![&env, broc_id, cabb_id, kohl_id, brsp_id],
                 (10 * 10_000_000) as i128,
+                None::<SupplyLimits>,
             ),
         );
         let kale_client = KaleSaladContractClient::new(&env, &kale_address);
 
-        return TestFixture {
+        TestFixture {
             env,
             admin,
             owners,
             vegetables,
             kale_salad_client: kale_client,
-        };
+        }
     }
 }
@@ -0,0 +1,91 @@
+use super::*;
+
+use crate::constants::MAXIMUM_URI_LENGTH;
+use crate::utils::{build_token_uri, u32_to_string, u64_to_string};
+
+/// A string of `len` repeated `a` characters
+fn long_string(env: &Env, len: usize) -> String {
+    let slice = [b'a'; MAXIMUM_URI_LENGTH];
+    String::from_bytes(env, &slice[..len])
+}
+
+#[test]
+fn test_u32_to_string() {
+    let env = Env::default();
+
+    assert_eq!(u32_to_string(&env, 0), String::from_str(&env, "0"));
+    assert_eq!(u32_to_string(&env, 9), String::from_str(&env, "9"));
+    assert_eq!(u32_to_string(&env, 10), String::from_str(&env, "10"));
+    assert_eq!(u32_to_string(&env, 99), String::from_str(&env, "99"));
+    assert_eq!(u32_to_string(&env, 100), String::from_str(&env, "100"));
+    assert_eq!(u32_to_string(&env, 255), String::from_str(&env, "255"));
+    assert_eq!(u32_to_string(&env, 256), String::from_str(&env, "256"));
+    assert_eq!(u32_to_string(&env, 1000), String::from_str(&env, "1000"));
+    assert_eq!(
+        u32_to_string(&env, u32::MAX),
+        String::from_str(&env, "4294967295")
+    );
+}
+
+#[test]
+fn test_u64_to_string() {
+    let env = Env::default();
+
+    assert_eq!(u64_to_string(&env, 0), String::from_str(&env, "0"));
+    assert_eq!(
+        u64_to_string(&env, u32::MAX as u64 + 1),
+        String::from_str(&env, "4294967296")
+    );
+    assert_eq!(
+        u64_to_string(&env, u64::MAX),
+        String::from_str(&env, "18446744073709551615")
+    );
+}
+
+#[test]
+fn test_build_token_uri() {
+    let env = Env::default();
+    let base_uri = String::from_str(&env, IPFS_URI);
+
+    assert_eq!(
+        build_token_uri::<MAXIMUM_URI_LENGTH>(&env, &base_uri, 4, &String::from_str(&env, "")),
+        String::from_str(&env, TOKEN_4_URI)
+    );
+    assert_eq!(
+        build_token_uri::<MAXIMUM_URI_LENGTH>(
+            &env,
+            &base_uri,
+            256,
+            &String::from_str(&env, ".json")
+        ),
+        String::from_str(
+            &env,
+            "ipfs://bafybeifmrpa7cpep7j6thdgs4dsteev7lvfwwvxuywofarnghdwevizdsa/256.json"
+        )
+    );
+}
+
+#[test]
+fn test_build_token_uri_at_maximum_length() {
+    let env = Env::default();
+    let suffix = String::from_str(&env, ".json");
+    // leave exactly enough room for the largest possible token id
+    let base_uri = long_string(&env, MAXIMUM_URI_LENGTH - 10 - 5);
+
+    let uri = build_token_uri::<MAXIMUM_URI_LENGTH>(&env, &base_uri, u32::MAX, &suffix);
+    assert_eq!(uri.len() as usize, MAXIMUM_URI_LENGTH);
+
+    let mut slice = [0u8; MAXIMUM_URI_LENGTH];
+    uri.copy_into_slice(&mut slice);
+    assert_eq!(&slice[MAXIMUM_URI_LENGTH - 15..], b"4294967295.json");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_cannot_build_token_uri_over_maximum_length() {
+    let env = Env::default();
+    let suffix = String::from_str(&env, ".json");
+    let base_uri = long_string(&env, MAXIMUM_URI_LENGTH - 10 - 5 + 1);
+
+    build_token_uri::<MAXIMUM_URI_LENGTH>(&env, &base_uri, u32::MAX, &suffix);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, Map, String};
 
 #[contracttype]
 pub struct Metadata {
@@ -13,17 +13,64 @@ pub struct ApprovedData {
     pub expiration_ledger: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub address: Address,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SaladRecipe {
+    pub ingredients: Map<Address, i128>, // vegetable SAC address => stroops burned
+    pub mint_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VegetableLimits {
+    pub minimum: i128,
+    pub maximum: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupplyLimits {
+    pub max_supply: u32,
+    pub max_per_address: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PauseState {
+    pub minting: bool,
+    pub transfers: bool,
+    pub burns: bool,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum Storage {
     Admin,                // : address
+    PendingAdmin,         // : PendingAdmin
     Metadata,             // : Metadata
+    UriSuffix,            // : string
+    Paused,               // : PauseState
     Vegetables,           // : vec<address>
     PaymentPerNft,        // : i128
+    CustomPriceFloor,     // : i128
+    Limits(Address),      // : VegetableLimits
     Supply,               // : u32
     MintIndex,            // : u32
+    TokenByIndex(u32),    // : u32
+    TokenIndex(u32),      // : u32
+    MaxSupply,            // : u32
+    MaxPerAddress,        // : u32
     Balance(Address),     // : vec<u32>
     Owner(u32),           // : address
+    Recipe(u32),          // : SaladRecipe
+    TokenUri(u32),        // : string
     Approved(u32),        // : ApprovedData
     ApprovedAll(Address), // : ApprovedData
 }
@@ -1,27 +1,80 @@
-use soroban_sdk::{Env, String};
+use soroban_sdk::{panic_with_error, Env, String};
 
-pub fn u8_to_string(env: &Env, value: u8) -> String {
+use crate::errors::Errors;
+
+/// The most decimal digits a `u64` (18446744073709551615) can have
+pub const MAXIMUM_U64_DIGITS: usize = 20;
+/// The most decimal digits a `u32` (4294967295) can have
+pub const MAXIMUM_U32_DIGITS: usize = 10;
+
+/// Writes the decimal digits of `value` to the start of `slice`, returning the
+/// number of digits written
+fn write_decimal(slice: &mut [u8; MAXIMUM_U64_DIGITS], value: u64) -> usize {
     if value == 0 {
-        return String::from_str(env, &"0");
+        slice[0] = b'0';
+        return 1;
     }
 
     let mut num_digits: usize = 0;
-    let mut temp: u8 = value.clone();
+    let mut temp: u64 = value;
 
     while temp != 0 {
         num_digits += 1;
         temp /= 10;
     }
 
-    let mut slice: [u8; 3] = [0u8; 3];
     let mut index = num_digits;
-    temp = value.clone();
+    temp = value;
 
     while temp != 0 {
         index -= 1;
-        slice[index] = 48 + temp % 10;
+        slice[index] = b'0' + (temp % 10) as u8;
         temp /= 10;
     }
 
-    return String::from_bytes(&env, &slice[..num_digits]);
+    num_digits
+}
+
+pub fn u64_to_string(env: &Env, value: u64) -> String {
+    let mut slice = [0u8; MAXIMUM_U64_DIGITS];
+    let num_digits = write_decimal(&mut slice, value);
+
+    String::from_bytes(env, &slice[..num_digits])
+}
+
+pub fn u32_to_string(env: &Env, value: u32) -> String {
+    u64_to_string(env, value as u64)
+}
+
+/// Joins `base_uri`, the decimal `token_id`, and `suffix` into a single URI no
+/// longer than `N` bytes, panicking with `UriTooLong` if it won't fit
+pub fn build_token_uri<const N: usize>(
+    env: &Env,
+    base_uri: &String,
+    token_id: u32,
+    suffix: &String,
+) -> String {
+    let token_id_str = u32_to_string(env, token_id);
+
+    let base_uri_len = base_uri.len() as usize;
+    let suffix_start = base_uri_len + token_id_str.len() as usize;
+    let combined_len = suffix_start + suffix.len() as usize;
+    if combined_len > N {
+        panic_with_error!(env, Errors::UriTooLong);
+    }
+
+    let mut slice = [0u8; N];
+    base_uri.copy_into_slice(&mut slice[..base_uri_len]);
+    token_id_str.copy_into_slice(&mut slice[base_uri_len..suffix_start]);
+    suffix.copy_into_slice(&mut slice[suffix_start..combined_len]);
+
+    String::from_bytes(env, &slice[..combined_len])
+}
+
+/// Makes sure a URI built from `base_uri` and `suffix` will fit in `N` bytes,
+/// no matter which `token_id` goes in between
+pub fn check_uri_fits<const N: usize>(env: &Env, base_uri: &String, suffix: &String) {
+    if base_uri.len() as usize + MAXIMUM_U32_DIGITS + suffix.len() as usize > N {
+        panic_with_error!(env, Errors::UriTooLong);
+    }
 }
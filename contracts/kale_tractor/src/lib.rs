@@ -1,8 +1,9 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contractmeta, panic_with_error,
-    symbol_short, vec, Address, Env, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contractmeta, contracttype,
+    panic_with_error, symbol_short, token, vec, xdr::ScErrorType, Address, BytesN, Env, Symbol,
+    Vec,
 };
 
 contractmeta!(key = "title", val = "KaleFail Tractor",);
@@ -12,7 +13,14 @@ contractmeta!(
 );
 contractmeta!(key = "binver", val = "2.0.0",);
 
+const ADMIN: Symbol = symbol_short!("ADMIN");
 const FARM: Symbol = symbol_short!("FARM");
+const KALE: Symbol = symbol_short!("KALE");
+const TRADING_POST: Symbol = symbol_short!("POST");
+const FEE: Symbol = symbol_short!("FEE");
+const BASIS_POINTS: u32 = 10_000;
+const MAX_PAILS: Symbol = symbol_short!("MAXPAILS");
+const DEFAULT_MAX_PAILS: u32 = 50;
 const DAY_OF_LEDGERS: u32 = 60 * 60 * 24 / 5; // assumes 5-second ledger close times
 const WEEK_OF_LEDGERS: u32 = DAY_OF_LEDGERS * 7;
 
@@ -24,13 +32,62 @@ pub enum Error {
     NoPailsProvided = 1,
     /// Harvesting all pails results in 0 reward
     NoHarvestablePails = 2,
+    /// More pails provided in invocation than the tractor will harvest at once
+    TooManyPails = 3,
+    /// Operator fee is more than 10,000 basis points (100%)
+    InvalidFee = 4,
+    /// Stake to replant is more than 10,000 basis points (100%)
+    InvalidStake = 5,
+    /// Split across vegetables adds up to more than 10,000 basis points (100%)
+    InvalidSplit = 6,
+    /// No trading post has been set for the tractor to trade through
+    NoTradingPost = 7,
+    /// Range of pails ends before it starts
+    InvalidRange = 8,
+}
+
+/// The cut of each farmer's harvest the tractor's operator takes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperatorFee {
+    pub operator: Address, // who the fee is paid to
+    pub fee: u32,          // basis points of the harvested KALE
+}
+
+/// What happened when the tractor tried to harvest a pail
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HarvestOutcome {
+    /// The pail was harvested, for this reward
+    Harvested(i128),
+    /// The farm refused, with this contract error code (e.g. the pail isn't
+    /// ready yet, or has already been harvested)
+    FarmError(u32),
+    /// The farm failed for some other reason, such as running out of budget
+    HostError,
+}
+
+/// The rewards from harvesting for several farmers at once
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HarvestBatch {
+    pub rewards: Vec<Vec<i128>>, // each farmer's rewards, in the order requested
+    pub totals: Vec<i128>,       // each farmer's total reward
+    pub total: i128,             // the total reward across all farmers
 }
 
 #[contractclient(name = "FarmClient")]
 pub trait Farm {
+    fn plant(env: Env, farmer: Address, amount: i128);
+    fn work(env: Env, farmer: Address, hash: BytesN<32>, nonce: u64) -> u32;
     fn harvest(env: Env, farmer: Address, index: u32) -> i128;
 }
 
+#[contractclient(name = "TradingPostClient")]
+pub trait TradingPost {
+    fn trade(env: Env, customer: Address, vegetable: Address, amount: i128, buy_kale: bool);
+}
+
 pub fn extend_instance_ttl(env: &Env) {
     let max_ttl = env.storage().max_ttl();
     env.storage()
@@ -43,53 +100,527 @@ pub struct KaleTractorContract;
 
 #[contractimpl]
 impl KaleTractorContract {
-    pub fn __constructor(env: Env, farm: Address) {
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        farm: Address,
+        kale: Address,
+        max_pails: Option<u32>,
+        operator_fee: Option<OperatorFee>,
+    ) {
+        // require authorization from the admin address.
+        admin.require_auth();
+
+        if let Some(operator_fee) = &operator_fee {
+            if operator_fee.fee > BASIS_POINTS {
+                panic_with_error!(&env, Error::InvalidFee);
+            }
+        }
+
+        env.storage().instance().set(&ADMIN, &admin);
         env.storage().instance().set(&FARM, &farm);
+        env.storage().instance().set(&KALE, &kale);
+        env.storage()
+            .instance()
+            .set(&MAX_PAILS, &max_pails.unwrap_or(DEFAULT_MAX_PAILS));
+        env.storage().instance().set(&FEE, &operator_fee);
+        extend_instance_ttl(&env);
+    }
+
+    /// Upgrade the contract's Wasm bytecode.
+    ///
+    /// # Arguments
+    /// - `new_wasm_hash` - hash identifier of some Wasm bytecode already
+    ///   installed on-chain
+    ///
+    /// # Panics
+    /// - If the admin hasn't authorized the upgrade
+    /// - If the Wasm bytecode is not already installed on-chain
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        extend_instance_ttl(&env);
+    }
+
+    /// Point the tractor at a different KALE farm contract, such as when the
+    /// farm is redeployed, so the tractor's own address can stay the same.
+    ///
+    /// # Arguments
+    /// - `new_farm` - address of the farm contract to harvest from
+    ///
+    /// # Panics
+    /// - If the admin hasn't authorized the change
+    ///
+    /// # Events
+    /// Emits an event with:
+    /// - topics - `["set_farm", admin: Address]`
+    /// - data - `[old_farm: Address, new_farm: Address]`
+    pub fn set_farm(env: Env, new_farm: Address) {
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        admin.require_auth();
+
+        let old_farm: Address = env.storage().instance().get(&FARM).unwrap();
+        env.storage().instance().set(&FARM, &new_farm);
+
+        env.events()
+            .publish((symbol_short!("set_farm"), admin), (old_farm, new_farm));
+
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the trading post `harvest_and_trade` trades KALE through, or `None`
+    /// to stop trading.
+    ///
+    /// # Arguments
+    /// - `trading_post` - address of the trading post contract
+    ///
+    /// # Panics
+    /// - If the admin hasn't authorized the change
+    pub fn set_trading_post(env: Env, trading_post: Option<Address>) {
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&TRADING_POST, &trading_post);
+
         extend_instance_ttl(&env);
     }
 
+    /// The trading post `harvest_and_trade` trades KALE through, if any.
+    pub fn trading_post(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&TRADING_POST)
+            .unwrap_or_default()
+    }
+
+    /// The KALE farm contract the tractor harvests from.
+    pub fn farm(env: Env) -> Address {
+        env.storage().instance().get(&FARM).unwrap()
+    }
+
+    /// The fee the tractor's operator takes from each harvest, if any.
+    pub fn operator_fee(env: Env) -> Option<OperatorFee> {
+        env.storage().instance().get(&FEE).unwrap()
+    }
+
+    /// The most pails `harvest_many` and `harvest_range` will harvest in one
+    /// invocation.
+    pub fn max_pails(env: Env) -> u32 {
+        env.storage().instance().get(&MAX_PAILS).unwrap()
+    }
+
     /// Harvest multiple pails available for your KALE farmer.
     ///
     /// # Arguments
     /// - `farmer` - address of the farmer to harvest on behalf of
     /// - `pails` - vector of pails which should be harvested
     ///
+    /// If the tractor charges an operator fee, it's pulled from the farmer's
+    /// harvested KALE using an allowance the farmer has given the tractor.
+    ///
     /// # Panics
     /// - If the `pails` vector is empty
     /// - If no pails result in a non-zero reward
+    /// - If the farmer's allowance doesn't cover the operator fee
+    ///
+    /// # Events
+    /// If an operator fee is charged, emits an event with:
+    /// - topics - `["fee", farmer: Address, operator: Address]`
+    /// - data - `[gross: i128, fee: i128, net: i128]`
     pub fn harvest(env: Env, farmer: Address, pails: Vec<u32>) -> Vec<i128> {
-        if pails.len() == 0 {
+        if pails.is_empty() {
+            panic_with_error!(&env, Error::NoPailsProvided);
+        }
+
+        let farm_client = FarmClient::new(&env, &Self::farm(env.clone()));
+
+        let rewards = harvest_pails(&env, &farm_client, &farmer, &pails);
+
+        // make sure we've at least harvested _some_ kale
+        if !rewards.iter().any(|reward| reward > 0) {
+            panic_with_error!(&env, Error::NoHarvestablePails);
+        };
+
+        charge_fee(&env, &farmer, rewards.iter().sum());
+
+        extend_instance_ttl(&env);
+
+        rewards
+    }
+
+    /// Harvest multiple pails available for your KALE farmer, then plant some
+    /// of the harvest straight back into the farm's current block, saving a
+    /// transaction (and maybe a block) between the two.
+    ///
+    /// # Arguments
+    /// - `farmer` - address of the farmer to harvest on behalf of
+    /// - `pails` - vector of pails which should be harvested
+    /// - `stake` - basis points of the harvested KALE, after any operator
+    ///   fee, to plant
+    ///
+    /// # Panics
+    /// - If the farmer hasn't authorized the invocation, including the plant
+    /// - If `stake` is more than 10,000 basis points
+    /// - For any of the reasons `harvest` would panic
+    /// - If the farm won't let the farmer plant, such as when they already
+    ///   have in the current block
+    ///
+    /// # Events
+    /// Emits the same fee event as `harvest`, if an operator fee is charged.
+    pub fn harvest_and_replant(
+        env: Env,
+        farmer: Address,
+        pails: Vec<u32>,
+        stake: u32,
+    ) -> (Vec<i128>, i128) {
+        // planting spends the farmer's KALE, so they need to be the one asking
+        farmer.require_auth();
+
+        if stake > BASIS_POINTS {
+            panic_with_error!(&env, Error::InvalidStake);
+        }
+        if pails.is_empty() {
+            panic_with_error!(&env, Error::NoPailsProvided);
+        }
+
+        let farm_client = FarmClient::new(&env, &Self::farm(env.clone()));
+
+        let rewards = harvest_pails(&env, &farm_client, &farmer, &pails);
+
+        // make sure we've at least harvested _some_ kale
+        if !rewards.iter().any(|reward| reward > 0) {
+            panic_with_error!(&env, Error::NoHarvestablePails);
+        };
+
+        let net = charge_fee(&env, &farmer, rewards.iter().sum());
+
+        // plant the farmer's share of what they've just harvested
+        let planted = net * stake as i128 / BASIS_POINTS as i128;
+        farm_client.plant(&farmer, &planted);
+
+        extend_instance_ttl(&env);
+
+        (rewards, planted)
+    }
+
+    /// Harvest multiple pails available for your KALE farmer, then trade some
+    /// of the harvest for vegetables at the tractor's trading post. Whatever
+    /// isn't split between the vegetables is kept as KALE.
+    ///
+    /// # Arguments
+    /// - `farmer` - address of the farmer to harvest on behalf of
+    /// - `pails` - vector of pails which should be harvested
+    /// - `split` - vector of vegetables, each with the basis points of the
+    ///   harvested KALE, after any operator fee, to trade for it
+    ///
+    /// # Panics
+    /// - If the farmer hasn't authorized the invocation, including the trades
+    /// - If no trading post has been set
+    /// - If the `split` adds up to more than 10,000 basis points
+    /// - For any of the reasons `harvest` would panic
+    /// - If the trading post won't make one of the trades
+    ///
+    /// # Events
+    /// Emits the same fee event as `harvest`, if an operator fee is charged.
+    pub fn harvest_and_trade(
+        env: Env,
+        farmer: Address,
+        pails: Vec<u32>,
+        split: Vec<(Address, u32)>,
+    ) -> (Vec<i128>, Vec<i128>) {
+        // trading spends the farmer's KALE, so they need to be the one asking
+        farmer.require_auth();
+
+        let Some(trading_post) = Self::trading_post(env.clone()) else {
+            panic_with_error!(&env, Error::NoTradingPost);
+        };
+        // shares big enough to overflow can't add up to a valid split either
+        let total_share = split
+            .iter()
+            .try_fold(0u32, |total, (_, share)| total.checked_add(share))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidSplit));
+        if total_share > BASIS_POINTS {
+            panic_with_error!(&env, Error::InvalidSplit);
+        }
+        if pails.is_empty() {
+            panic_with_error!(&env, Error::NoPailsProvided);
+        }
+
+        let farm_client = FarmClient::new(&env, &Self::farm(env.clone()));
+
+        let rewards = harvest_pails(&env, &farm_client, &farmer, &pails);
+
+        // make sure we've at least harvested _some_ kale
+        if !rewards.iter().any(|reward| reward > 0) {
+            panic_with_error!(&env, Error::NoHarvestablePails);
+        };
+
+        let net = charge_fee(&env, &farmer, rewards.iter().sum());
+
+        // trade each vegetable's share of the harvest, and see what it got
+        let trading_post_client = TradingPostClient::new(&env, &trading_post);
+        let mut vegetables: Vec<i128> = vec![&env];
+        split.iter().for_each(|(vegetable, share)| {
+            let amount = net * share as i128 / BASIS_POINTS as i128;
+            if amount == 0 {
+                vegetables.push_back(0);
+                return;
+            }
+
+            let vegetable_client = token::Client::new(&env, &vegetable);
+            let balance = vegetable_client.balance(&farmer);
+            trading_post_client.trade(&farmer, &vegetable, &amount, &false);
+            vegetables.push_back(vegetable_client.balance(&farmer) - balance);
+        });
+
+        extend_instance_ttl(&env);
+
+        (rewards, vegetables)
+    }
+
+    /// Harvest multiple pails available for your KALE farmer, reporting what
+    /// happened with each one. Unlike `harvest`, this doesn't panic if nothing
+    /// could be harvested, so the caller can tell the pails worth trying
+    /// again later apart from those to give up on.
+    ///
+    /// # Arguments
+    /// - `farmer` - address of the farmer to harvest on behalf of
+    /// - `pails` - vector of pails which should be harvested
+    ///
+    /// # Panics
+    /// - If the `pails` vector is empty
+    /// - If the farmer's allowance doesn't cover the operator fee
+    ///
+    /// # Events
+    /// Emits an event with:
+    /// - topics - `["harvest", farmer: Address]`
+    /// - data - `[harvested: u32, farm_errors: u32, host_errors: u32, reward: i128]`
+    ///
+    /// And the same fee event as `harvest`, if an operator fee is charged.
+    pub fn harvest_detailed(env: Env, farmer: Address, pails: Vec<u32>) -> Vec<HarvestOutcome> {
+        if pails.is_empty() {
             panic_with_error!(&env, Error::NoPailsProvided);
         }
 
-        let mut rewards: Vec<i128> = vec![&env];
-        let mut at_least_one_nonzero: bool = false;
+        let farm_client = FarmClient::new(&env, &Self::farm(env.clone()));
 
-        let farm_address: Address = env.storage().instance().get(&FARM).unwrap();
-        let farm_client = FarmClient::new(&env, &farm_address);
+        let mut outcomes: Vec<HarvestOutcome> = vec![&env];
+        let (mut harvested, mut farm_errors, mut host_errors, mut reward) =
+            (0u32, 0u32, 0u32, 0i128);
 
-        // iterate through pails provided
         pails.iter().for_each(|pail| {
-            // try to invoke the farm's `harvest` function
-            let reward = match farm_client.try_harvest(&farmer, &pail) {
-                Ok(Ok(number)) => number, // successful harvest, use the reward
-                _ => 0,                   // unsuccessful for some reason, use 0
-            };
-            rewards.push_back(reward);
-
-            if !at_least_one_nonzero && reward > 0 {
-                at_least_one_nonzero = true;
+            let outcome = try_harvest(&farm_client, &farmer, pail);
+            match outcome {
+                HarvestOutcome::Harvested(number) => {
+                    harvested += 1;
+                    reward += number;
+                }
+                HarvestOutcome::FarmError(_) => farm_errors += 1,
+                HarvestOutcome::HostError => host_errors += 1,
             }
+            outcomes.push_back(outcome);
         });
 
+        env.events().publish(
+            (symbol_short!("harvest"), farmer.clone()),
+            (harvested, farm_errors, host_errors, reward),
+        );
+
+        charge_fee(&env, &farmer, reward);
+
+        extend_instance_ttl(&env);
+
+        outcomes
+    }
+
+    /// Harvest whichever pails in a range of indexes are ready for your KALE
+    /// farmer, without needing to know which ones they are. Pails which can't
+    /// be harvested are skipped, until too many fail in a row.
+    ///
+    /// # Arguments
+    /// - `farmer` - address of the farmer to harvest on behalf of
+    /// - `from_index` - the first pail index to try
+    /// - `to_index` - the last pail index to try
+    /// - `max_attempts` - how many pails in a row can fail to be harvested
+    ///   before giving up on the rest of the range, or 0 to try them all
+    ///
+    /// # Panics
+    /// - If `to_index` is before `from_index`
+    /// - If the range holds more pails than `max_pails`
+    /// - If no pails result in a non-zero reward
+    /// - If the farmer's allowance doesn't cover the operator fee
+    ///
+    /// # Events
+    /// Emits the same fee event as `harvest`, if an operator fee is charged.
+    pub fn harvest_range(
+        env: Env,
+        farmer: Address,
+        from_index: u32,
+        to_index: u32,
+        max_attempts: u32,
+    ) -> Vec<(u32, i128)> {
+        if to_index < from_index {
+            panic_with_error!(&env, Error::InvalidRange);
+        }
+        if to_index - from_index >= Self::max_pails(env.clone()) {
+            panic_with_error!(&env, Error::TooManyPails);
+        }
+
+        let farm_client = FarmClient::new(&env, &Self::farm(env.clone()));
+
+        let mut harvested: Vec<(u32, i128)> = vec![&env];
+        let mut failures: u32 = 0;
+
+        // walk the range, until we run out or too many pails fail in a row
+        for pail in from_index..=to_index {
+            match try_harvest(&farm_client, &farmer, pail) {
+                HarvestOutcome::Harvested(reward) => {
+                    harvested.push_back((pail, reward));
+                    failures = 0;
+                }
+                _ => failures += 1,
+            }
+
+            if max_attempts != 0 && failures >= max_attempts {
+                break;
+            }
+        }
+
         // make sure we've at least harvested _some_ kale
-        if !at_least_one_nonzero {
+        let reward: i128 = harvested.iter().map(|(_, reward)| reward).sum();
+        if reward == 0 {
             panic_with_error!(&env, Error::NoHarvestablePails);
         };
 
+        charge_fee(&env, &farmer, reward);
+
         extend_instance_ttl(&env);
 
-        return rewards;
+        harvested
+    }
+
+    /// Harvest pails for several KALE farmers at once.
+    ///
+    /// # Arguments
+    /// - `requests` - vector of farmers, each with the pails which should be
+    ///   harvested for them
+    ///
+    /// # Panics
+    /// - If no pails are provided for any farmer
+    /// - If more pails are provided in total than `max_pails`
+    /// - If no pails, for any farmer, result in a non-zero reward
+    /// - If any farmer's allowance doesn't cover the operator fee
+    ///
+    /// # Events
+    /// Emits the same fee event as `harvest` for each farmer, if an operator
+    /// fee is charged. The rewards returned are before any fee.
+    pub fn harvest_many(env: Env, requests: Vec<(Address, Vec<u32>)>) -> HarvestBatch {
+        let pail_count: u32 = requests.iter().map(|(_, pails)| pails.len()).sum();
+        if pail_count == 0 {
+            panic_with_error!(&env, Error::NoPailsProvided);
+        }
+        if pail_count > Self::max_pails(env.clone()) {
+            panic_with_error!(&env, Error::TooManyPails);
+        }
+
+        let farm_client = FarmClient::new(&env, &Self::farm(env.clone()));
+
+        let mut batch = HarvestBatch {
+            rewards: vec![&env],
+            totals: vec![&env],
+            total: 0,
+        };
+
+        // harvest each farmer's pails in turn
+        requests.iter().for_each(|(farmer, pails)| {
+            let rewards = harvest_pails(&env, &farm_client, &farmer, &pails);
+            let total: i128 = rewards.iter().sum();
+            charge_fee(&env, &farmer, total);
+
+            batch.rewards.push_back(rewards);
+            batch.totals.push_back(total);
+            batch.total += total;
+        });
+
+        // make sure we've at least harvested _some_ kale
+        if batch.total == 0 {
+            panic_with_error!(&env, Error::NoHarvestablePails);
+        };
+
+        extend_instance_ttl(&env);
+
+        batch
+    }
+}
+
+/// Pulls the operator's fee, if the tractor charges one, out of the `gross`
+/// KALE `farmer` has just harvested, leaving them the net amount returned.
+fn charge_fee(env: &Env, farmer: &Address, gross: i128) -> i128 {
+    let Some(operator_fee) = env
+        .storage()
+        .instance()
+        .get::<_, Option<OperatorFee>>(&FEE)
+        .unwrap()
+    else {
+        return gross;
+    };
+
+    let fee = gross * operator_fee.fee as i128 / BASIS_POINTS as i128;
+    if fee == 0 {
+        return gross;
+    }
+
+    let kale_address: Address = env.storage().instance().get(&KALE).unwrap();
+    token::Client::new(env, &kale_address).transfer_from(
+        &env.current_contract_address(),
+        farmer,
+        &operator_fee.operator,
+        &fee,
+    );
+
+    env.events().publish(
+        (symbol_short!("fee"), farmer.clone(), operator_fee.operator),
+        (gross, fee, gross - fee),
+    );
+
+    gross - fee
+}
+
+/// Harvests each of the `pails` for `farmer`, with a reward of 0 for any
+/// which can't be harvested.
+fn harvest_pails(
+    env: &Env,
+    farm_client: &FarmClient,
+    farmer: &Address,
+    pails: &Vec<u32>,
+) -> Vec<i128> {
+    let mut rewards: Vec<i128> = vec![env];
+
+    // iterate through pails provided
+    pails.iter().for_each(|pail| {
+        // try to invoke the farm's `harvest` function
+        let reward = match try_harvest(farm_client, farmer, pail) {
+            HarvestOutcome::Harvested(number) => number, // successful harvest, use the reward
+            _ => 0,                                      // unsuccessful for some reason, use 0
+        };
+        rewards.push_back(reward);
+    });
+
+    rewards
+}
+
+/// Tries to invoke the farm's `harvest` function for one pail, and works out
+/// why it failed if it didn't succeed.
+fn try_harvest(farm_client: &FarmClient, farmer: &Address, pail: u32) -> HarvestOutcome {
+    match farm_client.try_harvest(farmer, &pail) {
+        Ok(Ok(number)) => HarvestOutcome::Harvested(number),
+        Err(Ok(error)) if error.is_type(ScErrorType::Contract) => {
+            HarvestOutcome::FarmError(error.get_code())
+        }
+        _ => HarvestOutcome::HostError,
     }
 }
 
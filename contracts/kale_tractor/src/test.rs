@@ -1,13 +1,193 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    token, Env, IntoVal,
+};
 
 mod homestead_contract {
     use soroban_sdk::auth::Context;
     soroban_sdk::contractimport!(file = "kale_homestead.wasm");
 }
 
+mod mock_farm {
+    use soroban_sdk::{
+        contract, contracterror, contractimpl, panic_with_error, symbol_short, token, Address, Env,
+    };
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+    #[repr(u32)]
+    pub enum FarmError {
+        PailMissing = 1,
+    }
+
+    /// A farm with pails ready to harvest whenever a test says so, rather than
+    /// needing the work to be done first. A negative reward stands in for the
+    /// farm falling over part way through a harvest.
+    #[contract]
+    pub struct MockFarm;
+
+    #[contractimpl]
+    impl MockFarm {
+        pub fn __constructor(env: Env, kale: Address) {
+            env.storage().instance().set(&symbol_short!("KALE"), &kale);
+        }
+
+        pub fn set_reward(env: Env, farmer: Address, index: u32, reward: i128) {
+            env.storage().persistent().set(&(farmer, index), &reward);
+        }
+
+        pub fn plant(env: Env, farmer: Address, amount: i128) {
+            farmer.require_auth();
+
+            let kale: Address = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("KALE"))
+                .unwrap();
+            token::Client::new(&env, &kale).burn(&farmer, &amount);
+            env.storage().instance().set(&farmer, &amount);
+        }
+
+        pub fn planted(env: Env, farmer: Address) -> Option<i128> {
+            env.storage().instance().get(&farmer)
+        }
+
+        pub fn harvest(env: Env, farmer: Address, index: u32) -> i128 {
+            let key = (farmer, index);
+            let Some(reward) = env.storage().persistent().get(&key) else {
+                panic_with_error!(&env, FarmError::PailMissing);
+            };
+            env.storage().persistent().remove(&key);
+
+            if reward < 0 {
+                panic!("the farm fell over");
+            }
+
+            if reward > 0 {
+                let kale: Address = env
+                    .storage()
+                    .instance()
+                    .get(&symbol_short!("KALE"))
+                    .unwrap();
+                token::StellarAssetClient::new(&env, &kale).mint(&key.0, &reward);
+            }
+
+            reward
+        }
+    }
+}
+
+mod mock_trading_post {
+    use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env};
+
+    /// A trading post which takes KALE and hands out twice as much of any
+    /// vegetable it's the SAC admin of.
+    #[contract]
+    pub struct MockTradingPost;
+
+    #[contractimpl]
+    impl MockTradingPost {
+        pub fn __constructor(env: Env, kale: Address) {
+            env.storage().instance().set(&symbol_short!("KALE"), &kale);
+        }
+
+        pub fn trade(
+            env: Env,
+            customer: Address,
+            vegetable: Address,
+            amount: i128,
+            buy_kale: bool,
+        ) {
+            customer.require_auth();
+            assert!(!buy_kale, "the tractor only buys vegetables");
+
+            let kale: Address = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("KALE"))
+                .unwrap();
+            token::Client::new(&env, &kale).transfer(
+                &customer,
+                &env.current_contract_address(),
+                &amount,
+            );
+            token::StellarAssetClient::new(&env, &vegetable).mint(&customer, &(amount * 2));
+        }
+    }
+}
+
+struct TestFixture {
+    env: Env,
+    admin: Address,
+    operator: Address,
+    farmers: Vec<Address>,
+    farm_client: mock_farm::MockFarmClient<'static>,
+    kale_client: token::Client<'static>,
+    tractor_client: KaleTractorContractClient<'static>,
+}
+
+impl TestFixture {
+    /// Sets up a tractor on a mock farm, with `rewards` ready for each of the
+    /// farmers at pails 0, 1, 2... and an operator `fee` in basis points, if
+    /// the tractor should charge one.
+    fn create(rewards: &[&[i128]], fee: Option<u32>) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let operator = Address::generate(&env);
+
+        let kale = env.register_stellar_asset_contract_v2(admin.clone());
+        let farm_address = create_mock_farm(&env, &kale.address());
+        let farm_client = mock_farm::MockFarmClient::new(&env, &farm_address);
+
+        let mut farmers = vec![&env];
+        for farmer_rewards in rewards {
+            let farmer = Address::generate(&env);
+            for (index, reward) in farmer_rewards.iter().enumerate() {
+                farm_client.set_reward(&farmer, &(index as u32), reward);
+            }
+            farmers.push_back(farmer);
+        }
+
+        let operator_fee = fee.map(|fee| OperatorFee {
+            operator: operator.clone(),
+            fee,
+        });
+        let tractor_address = env.register(
+            KaleTractorContract,
+            (
+                admin.clone(),
+                farm_address,
+                kale.address(),
+                Some(5u32),
+                operator_fee,
+            ),
+        );
+
+        TestFixture {
+            kale_client: token::Client::new(&env, &kale.address()),
+            tractor_client: KaleTractorContractClient::new(&env, &tractor_address),
+            env,
+            admin,
+            operator,
+            farmers,
+            farm_client,
+        }
+    }
+}
+
+/// Registers a mock farm which pays out in `kale`, so must be its SAC admin.
+fn create_mock_farm(env: &Env, kale: &Address) -> Address {
+    let farm_address = env.register(mock_farm::MockFarm, (kale.clone(),));
+    token::StellarAssetClient::new(env, kale).set_admin(&farm_address);
+
+    farm_address
+}
+
 #[test]
 fn test_constructor() {
     let env = Env::default();
@@ -16,9 +196,18 @@ fn test_constructor() {
     let admin = Address::generate(&env);
 
     let kale = env.register_stellar_asset_contract_v2(admin.clone());
-    let homestead_address = env.register(homestead_contract::WASM, (admin, kale.address()));
+    let homestead_address = env.register(homestead_contract::WASM, (admin.clone(), kale.address()));
 
-    env.register(KaleTractorContract, (homestead_address,));
+    env.register(
+        KaleTractorContract,
+        (
+            admin.clone(),
+            homestead_address,
+            kale.address(),
+            None::<u32>,
+            None::<OperatorFee>,
+        ),
+    );
 }
 
 #[test]
@@ -31,9 +220,18 @@ fn test_with_no_pails_provided() {
     let farmer = Address::generate(&env);
 
     let kale = env.register_stellar_asset_contract_v2(admin.clone());
-    let homestead_address = env.register(homestead_contract::WASM, (admin, kale.address()));
+    let homestead_address = env.register(homestead_contract::WASM, (admin.clone(), kale.address()));
 
-    let tractor_address = env.register(KaleTractorContract, (homestead_address,));
+    let tractor_address = env.register(
+        KaleTractorContract,
+        (
+            admin.clone(),
+            homestead_address,
+            kale.address(),
+            None::<u32>,
+            None::<OperatorFee>,
+        ),
+    );
     let tractor_client = KaleTractorContractClient::new(&env, &tractor_address);
     tractor_client.harvest(&farmer, &vec![&env]);
 }
@@ -41,19 +239,32 @@ fn test_with_no_pails_provided() {
 #[test]
 fn test_with_all_harvestable_pails() {
     let env = Env::from_ledger_snapshot_file("snapshot.json");
+    env.mock_all_auths();
 
     let homestead_address = Address::from_str(
         &env,
-        &"CDL74RF5BLYR2YBLCCI7F5FB6TPSCLKEJUBSD2RSVWZ4YHF3VMFAIGWA",
+        "CDL74RF5BLYR2YBLCCI7F5FB6TPSCLKEJUBSD2RSVWZ4YHF3VMFAIGWA",
     );
 
-    let tractor_address = env.register(KaleTractorContract, (homestead_address,));
+    // without an operator fee, the tractor never touches KALE itself
+    let admin = Address::generate(&env);
+    let kale_address = Address::generate(&env);
+    let tractor_address = env.register(
+        KaleTractorContract,
+        (
+            admin.clone(),
+            homestead_address,
+            kale_address,
+            None::<u32>,
+            None::<OperatorFee>,
+        ),
+    );
     let tractor_client = KaleTractorContractClient::new(&env, &tractor_address);
 
     // these pails, for this user, just so happen to be present and harvestable given the snapshot file.
     let farmer = Address::from_str(
         &env,
-        &"GBQZZ54DGBXZ6USGU5PMMAJVDLALGEMXXZEZC5BDDSAG7FI653DLMVTN",
+        "GBQZZ54DGBXZ6USGU5PMMAJVDLALGEMXXZEZC5BDDSAG7FI653DLMVTN",
     );
     let rewards = tractor_client.harvest(
         &farmer,
@@ -76,9 +287,18 @@ fn test_with_all_unharvestable_pails() {
     let farmer = Address::generate(&env);
 
     let kale = env.register_stellar_asset_contract_v2(admin.clone());
-    let homestead_address = env.register(homestead_contract::WASM, (admin, kale.address()));
+    let homestead_address = env.register(homestead_contract::WASM, (admin.clone(), kale.address()));
 
-    let tractor_address = env.register(KaleTractorContract, (homestead_address,));
+    let tractor_address = env.register(
+        KaleTractorContract,
+        (
+            admin.clone(),
+            homestead_address,
+            kale.address(),
+            None::<u32>,
+            None::<OperatorFee>,
+        ),
+    );
     let tractor_client = KaleTractorContractClient::new(&env, &tractor_address);
     tractor_client.harvest(&farmer, &vec![&env, 0, 1, 2, 3]);
 }
@@ -86,18 +306,31 @@ fn test_with_all_unharvestable_pails() {
 #[test]
 fn test_with_mixed_harvestable_pails() {
     let env = Env::from_ledger_snapshot_file("snapshot.json");
+    env.mock_all_auths();
 
     let homestead_address = Address::from_str(
         &env,
-        &"CDL74RF5BLYR2YBLCCI7F5FB6TPSCLKEJUBSD2RSVWZ4YHF3VMFAIGWA",
+        "CDL74RF5BLYR2YBLCCI7F5FB6TPSCLKEJUBSD2RSVWZ4YHF3VMFAIGWA",
     );
 
-    let tractor_address = env.register(KaleTractorContract, (homestead_address,));
+    // without an operator fee, the tractor never touches KALE itself
+    let admin = Address::generate(&env);
+    let kale_address = Address::generate(&env);
+    let tractor_address = env.register(
+        KaleTractorContract,
+        (
+            admin.clone(),
+            homestead_address,
+            kale_address,
+            None::<u32>,
+            None::<OperatorFee>,
+        ),
+    );
     let tractor_client = KaleTractorContractClient::new(&env, &tractor_address);
 
     let farmer = Address::from_str(
         &env,
-        &"GBQZZ54DGBXZ6USGU5PMMAJVDLALGEMXXZEZC5BDDSAG7FI653DLMVTN",
+        "GBQZZ54DGBXZ6USGU5PMMAJVDLALGEMXXZEZC5BDDSAG7FI653DLMVTN",
     );
     let rewards = tractor_client.harvest(
         &farmer,
@@ -109,3 +342,534 @@ fn test_with_mixed_harvestable_pails() {
     assert_eq!(rewards.iter().sum::<i128>(), 21566344);
     assert_eq!(rewards.len(), 10);
 }
+
+#[test]
+fn test_harvest_many() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10, 20], &[0, 5]], None);
+    let [first, second] = [farmers.get(0).unwrap(), farmers.get(1).unwrap()];
+
+    assert_eq!(tractor_client.max_pails(), 5);
+
+    // the second farmer's 0 reward and unknown pail don't spoil the batch
+    let batch = tractor_client.harvest_many(&vec![
+        &env,
+        (first, vec![&env, 0, 1]),
+        (second, vec![&env, 0, 1, 7]),
+    ]);
+
+    assert_eq!(
+        batch,
+        HarvestBatch {
+            rewards: vec![&env, vec![&env, 10, 20], vec![&env, 0, 5, 0]],
+            totals: vec![&env, 30, 5],
+            total: 35,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_harvest_many_with_too_many_pails() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10, 20, 30], &[5, 5, 5]], None);
+
+    tractor_client.harvest_many(&vec![
+        &env,
+        (farmers.get(0).unwrap(), vec![&env, 0, 1, 2]),
+        (farmers.get(1).unwrap(), vec![&env, 0, 1, 2]),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_harvest_many_with_no_pails_provided() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10]], None);
+
+    tractor_client.harvest_many(&vec![&env, (farmers.get(0).unwrap(), vec![&env])]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_harvest_many_with_all_unharvestable_pails() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[0], &[]], None);
+
+    tractor_client.harvest_many(&vec![
+        &env,
+        (farmers.get(0).unwrap(), vec![&env, 0]),
+        (farmers.get(1).unwrap(), vec![&env, 0, 1]),
+    ]);
+}
+
+#[test]
+fn test_harvest_detailed() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10, 0, -1]], None);
+    let farmer = farmers.get(0).unwrap();
+
+    let outcomes = tractor_client.harvest_detailed(&farmer, &vec![&env, 0, 1, 2, 3]);
+    assert_eq!(
+        outcomes,
+        vec![
+            &env,
+            HarvestOutcome::Harvested(10),
+            HarvestOutcome::Harvested(0),
+            HarvestOutcome::HostError,
+            HarvestOutcome::FarmError(1),
+        ]
+    );
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                tractor_client.address.clone(),
+                (symbol_short!("harvest"), farmer.clone()).into_val(&env),
+                (2u32, 1u32, 1u32, 10i128).into_val(&env),
+            ),
+        ]
+    );
+
+    // harvested pails are gone, but nothing panics when none are left
+    let outcomes = tractor_client.harvest_detailed(&farmer, &vec![&env, 0]);
+    assert_eq!(outcomes, vec![&env, HarvestOutcome::FarmError(1)]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_harvest_detailed_with_no_pails_provided() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10]], None);
+
+    tractor_client.harvest_detailed(&farmers.get(0).unwrap(), &vec![&env]);
+}
+
+#[test]
+fn test_harvest_with_operator_fee() {
+    let TestFixture {
+        env,
+        operator,
+        farmers,
+        kale_client,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[&[600, 400]], Some(250));
+    let farmer = farmers.get(0).unwrap();
+
+    // the farmer lets the tractor take its cut
+    kale_client.approve(
+        &farmer,
+        &tractor_client.address,
+        &100i128,
+        &(env.ledger().sequence() + 100),
+    );
+
+    let rewards = tractor_client.harvest(&farmer, &vec![&env, 0, 1]);
+    assert_eq!(rewards, vec![&env, 600, 400]);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                tractor_client.address.clone(),
+                (symbol_short!("fee"), farmer.clone(), operator.clone()).into_val(&env),
+                (1000i128, 25i128, 975i128).into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(kale_client.balance(&farmer), 975);
+    assert_eq!(kale_client.balance(&operator), 25);
+    assert_eq!(kale_client.allowance(&farmer, &tractor_client.address), 75);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_harvest_with_operator_fee_without_allowance() {
+    let TestFixture {
+        env,
+        farmers,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[&[600, 400]], Some(250));
+
+    tractor_client.harvest(&farmers.get(0).unwrap(), &vec![&env, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_constructor_requires_admin_auth() {
+    let env = Env::default();
+
+    env.register(
+        KaleTractorContract,
+        (
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            None::<u32>,
+            None::<OperatorFee>,
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_constructor_with_invalid_operator_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.register(
+        KaleTractorContract,
+        (
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            None::<u32>,
+            Some(OperatorFee {
+                operator: Address::generate(&env),
+                fee: 10_001,
+            }),
+        ),
+    );
+}
+
+#[test]
+fn test_harvest_range() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        kale_client,
+        ..
+    } = TestFixture::create(&[&[10, -1, 20, 0, 0, 0, 30]], None);
+    let farmer = farmers.get(0).unwrap();
+
+    // pail 1 falls over and is skipped
+    let harvested = tractor_client.harvest_range(&farmer, &0, &3, &2);
+    assert_eq!(harvested, vec![&env, (0, 10), (2, 20), (3, 0)]);
+    assert_eq!(kale_client.balance(&farmer), 30);
+
+    // pails 2 and 3 are gone now, but not too many fail in a row
+    let harvested = tractor_client.harvest_range(&farmer, &2, &6, &3);
+    assert_eq!(harvested, vec![&env, (4, 0), (5, 0), (6, 30)]);
+    assert_eq!(kale_client.balance(&farmer), 60);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_harvest_range_gives_up_after_max_attempts() {
+    let TestFixture {
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[-1, -1, -1, 10]], None);
+
+    tractor_client.harvest_range(&farmers.get(0).unwrap(), &0, &3, &3);
+}
+
+#[test]
+fn test_harvest_range_without_max_attempts() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[-1, -1, -1, 10]], None);
+
+    let harvested = tractor_client.harvest_range(&farmers.get(0).unwrap(), &0, &4, &0);
+    assert_eq!(harvested, vec![&env, (3, 10)]);
+}
+
+#[test]
+fn test_harvest_range_without_max_attempts_after_a_harvest() {
+    let TestFixture {
+        env,
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10, -1, 20]], None);
+
+    let harvested = tractor_client.harvest_range(&farmers.get(0).unwrap(), &0, &2, &0);
+    assert_eq!(harvested, vec![&env, (0, 10), (2, 20)]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_harvest_range_with_too_many_pails() {
+    let TestFixture {
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10]], None);
+
+    tractor_client.harvest_range(&farmers.get(0).unwrap(), &0, &5, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_harvest_range_ending_before_it_starts() {
+    let TestFixture {
+        tractor_client,
+        farmers,
+        ..
+    } = TestFixture::create(&[&[10, 20]], None);
+
+    tractor_client.harvest_range(&farmers.get(0).unwrap(), &1, &0, &0);
+}
+
+#[test]
+fn test_set_farm() {
+    let TestFixture {
+        env,
+        admin,
+        farmers,
+        kale_client,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[&[10]], None);
+    let farmer = farmers.get(0).unwrap();
+    let old_farm = tractor_client.farm();
+
+    // the farm is redeployed, with the farmer's pail moved along with it
+    let new_farm = create_mock_farm(&env, &kale_client.address);
+    mock_farm::MockFarmClient::new(&env, &new_farm).set_reward(&farmer, &0, &25);
+
+    tractor_client.set_farm(&new_farm);
+    assert_eq!(
+        env.auths()[0].0,
+        admin,
+        "the admin must authorize changing farms"
+    );
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                tractor_client.address.clone(),
+                (Symbol::new(&env, "set_farm"), admin).into_val(&env),
+                (old_farm, new_farm.clone()).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(tractor_client.farm(), new_farm);
+
+    let rewards = tractor_client.harvest(&farmer, &vec![&env, 0]);
+    assert_eq!(rewards, vec![&env, 25]);
+}
+
+#[test]
+fn test_upgrade() {
+    let TestFixture {
+        env,
+        admin,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[], None);
+
+    let wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(homestead_contract::WASM);
+    tractor_client.upgrade(&wasm_hash);
+    assert_eq!(env.auths()[0].0, admin, "the admin must authorize upgrades");
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_cannot_set_farm_without_admin() {
+    let TestFixture {
+        env,
+        farmers,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[&[10]], None);
+    let new_farm = Address::generate(&env);
+
+    // the farmer, rather than the admin, authorizes the change
+    env.mock_auths(&[MockAuth {
+        address: &farmers.get(0).unwrap(),
+        invoke: &MockAuthInvoke {
+            contract: &tractor_client.address,
+            fn_name: "set_farm",
+            args: (new_farm.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    tractor_client.set_farm(&new_farm);
+}
+
+#[test]
+fn test_harvest_and_replant() {
+    let TestFixture {
+        env,
+        operator,
+        farmers,
+        farm_client,
+        kale_client,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[&[600, 400]], Some(1000));
+    let farmer = farmers.get(0).unwrap();
+
+    kale_client.approve(
+        &farmer,
+        &tractor_client.address,
+        &100i128,
+        &(env.ledger().sequence() + 100),
+    );
+
+    // a quarter of the 900 KALE left after the fee goes back in the ground
+    let (rewards, planted) = tractor_client.harvest_and_replant(&farmer, &vec![&env, 0, 1], &2500);
+    assert_eq!(
+        env.auths()[0].0,
+        farmer,
+        "the farmer must authorize planting"
+    );
+    assert_eq!(rewards, vec![&env, 600, 400]);
+    assert_eq!(planted, 225);
+
+    assert_eq!(farm_client.planted(&farmer), Some(225));
+    assert_eq!(kale_client.balance(&farmer), 675);
+    assert_eq!(kale_client.balance(&operator), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_harvest_and_replant_with_invalid_stake() {
+    let TestFixture {
+        env,
+        farmers,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[&[600]], None);
+
+    tractor_client.harvest_and_replant(&farmers.get(0).unwrap(), &vec![&env, 0], &10_001);
+}
+
+/// Registers a mock trading post for the fixture's tractor to trade through,
+/// with a couple of vegetables on its shelves.
+fn create_mock_trading_post(fixture: &TestFixture) -> [token::Client<'static>; 2] {
+    let env = &fixture.env;
+    let trading_post = env.register(
+        mock_trading_post::MockTradingPost,
+        (fixture.kale_client.address.clone(),),
+    );
+    fixture
+        .tractor_client
+        .set_trading_post(&Some(trading_post.clone()));
+
+    [(); 2].map(|_| {
+        let vegetable = env.register_stellar_asset_contract_v2(fixture.admin.clone());
+        token::StellarAssetClient::new(env, &vegetable.address()).set_admin(&trading_post);
+        token::Client::new(env, &vegetable.address())
+    })
+}
+
+#[test]
+fn test_harvest_and_trade() {
+    let fixture = TestFixture::create(&[&[600, 400]], None);
+    let [broccoli, cabbage] = create_mock_trading_post(&fixture);
+    let env = fixture.env;
+    let farmer = fixture.farmers.get(0).unwrap();
+    let tractor_client = fixture.tractor_client;
+
+    // half for broccoli, a quarter for cabbage, and keep the rest
+    let (rewards, vegetables) = tractor_client.harvest_and_trade(
+        &farmer,
+        &vec![&env, 0, 1],
+        &vec![
+            &env,
+            (broccoli.address.clone(), 5000),
+            (cabbage.address.clone(), 2500),
+        ],
+    );
+    assert_eq!(
+        env.auths()[0].0,
+        farmer,
+        "the farmer must authorize trading"
+    );
+    assert_eq!(rewards, vec![&env, 600, 400]);
+    assert_eq!(vegetables, vec![&env, 1000, 500]);
+
+    assert_eq!(fixture.kale_client.balance(&farmer), 250);
+    assert_eq!(broccoli.balance(&farmer), 1000);
+    assert_eq!(cabbage.balance(&farmer), 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_harvest_and_trade_with_invalid_split() {
+    let fixture = TestFixture::create(&[&[600, 400]], None);
+    let [broccoli, cabbage] = create_mock_trading_post(&fixture);
+    let env = fixture.env;
+
+    fixture.tractor_client.harvest_and_trade(
+        &fixture.farmers.get(0).unwrap(),
+        &vec![&env, 0, 1],
+        &vec![
+            &env,
+            (broccoli.address.clone(), 7500),
+            (cabbage.address.clone(), 2501),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_harvest_and_trade_with_overflowing_split() {
+    let fixture = TestFixture::create(&[&[600, 400]], None);
+    let [broccoli, cabbage] = create_mock_trading_post(&fixture);
+    let env = fixture.env;
+
+    fixture.tractor_client.harvest_and_trade(
+        &fixture.farmers.get(0).unwrap(),
+        &vec![&env, 0, 1],
+        &vec![
+            &env,
+            (broccoli.address.clone(), u32::MAX),
+            (cabbage.address.clone(), u32::MAX),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_harvest_and_trade_without_trading_post() {
+    let TestFixture {
+        env,
+        farmers,
+        tractor_client,
+        ..
+    } = TestFixture::create(&[&[600, 400]], None);
+
+    assert_eq!(tractor_client.trading_post(), None);
+    tractor_client.harvest_and_trade(&farmers.get(0).unwrap(), &vec![&env, 0, 1], &vec![&env]);
+}
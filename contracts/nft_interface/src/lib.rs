@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{contractclient, Env, Address, String};
+use soroban_sdk::{contractclient, Env, Address, String, Vec};
 
 #[contractclient(name = "NonFungibleTokenClient")]
 pub trait NonFungibleTokenInterface {
@@ -9,7 +9,7 @@ pub trait NonFungibleTokenInterface {
     /// # Arguments
     ///
     /// - `owner` - The address for which a balance is being queried. If the
-    /// address has no existing NFTs, returns 0.
+    ///   address has no existing NFTs, returns 0.
     fn balance(env: Env, owner: Address) -> u32;
 
     /// Find the owner of an NFT
@@ -161,3 +161,36 @@ pub trait NonFungibleTokenInterface {
     /// - If the NFT does not exist
     fn token_uri(env: Env, token_id: u32) -> String;
 }
+
+/// An optional extension to `NonFungibleTokenInterface`, allowing the NFTs in a
+/// contract (and those held by a particular owner) to be listed without having
+/// to replay the contract's events.
+#[contractclient(name = "NonFungibleTokenEnumerableClient")]
+pub trait NonFungibleTokenEnumerable {
+    /// Count all NFTs which currently exist (i.e., have been minted and not
+    /// yet burned).
+    fn total_supply(env: Env) -> u32;
+
+    /// List a page of the NFTs assigned to an owner
+    ///
+    /// # Arguments
+    ///
+    /// - `owner` - The address for which NFTs are being listed. If the address
+    ///   has no existing NFTs, returns an empty list.
+    /// - `start` - The position in the owner's list of NFTs to start from. If
+    ///   `start` is at or past the end of the list, returns an empty list.
+    /// - `limit` - The most NFTs to return.
+    fn tokens_of_owner(env: Env, owner: Address, start: u32, limit: u32) -> Vec<u32>;
+
+    /// Find the NFT at a position in the list of all existing NFTs
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - The position of the NFT, which must be less than
+    ///   `total_supply`.
+    ///
+    /// # Panics
+    ///
+    /// - If `index` is not less than `total_supply`
+    fn token_by_index(env: Env, index: u32) -> u32;
+}
@@ -1,13 +1,28 @@
 use crate::{
-    storage::{get_kale_address, get_vegetables_for_trade},
+    events::{emit_swap, emit_trade},
+    limits::{get_capacity, record_burn, record_mint, record_volume},
+    pool::{apply_buy, apply_sell, get_kale_reserve},
+    pricing::{
+        check_quote, collect_fee, price_buy, price_sell, quote_buy, quote_buy_exact_out,
+        quote_sell, quote_sell_exact_out, quote_swap_fee, Quote,
+    },
+    storage::{
+        get_exchange_rates, get_fee_destination, get_fees, get_inventory, get_kale_address,
+        get_limits, get_trade_mode, get_vegetable_kind, get_vegetables_for_trade,
+    },
+    types::{
+        Capacity, ExchangeRates, FeeDestination, FeeInfo, Inventory, Limits, Schedule, TradeMode,
+        VegetableKind,
+    },
     TradingPostContractArgs,
 };
-use soroban_sdk::{contractimpl, panic_with_error, token, Address, Env};
+use soroban_sdk::{contractimpl, panic_with_error, token, vec, Address, Env, Vec};
 
 use crate::{
     errors::Errors,
-    storage::{check_is_open, extend_instance_ttl},
-    CustomerTrait, TradingPostContract, TradingPostContractClient,
+    schedule::{check_in_season, check_is_open, get_schedule},
+    storage::extend_instance_ttl,
+    CustomerTrait, MintableClient, TradingPostContract, TradingPostContractClient,
 };
 
 /// A customer interface for a trading post contract. Swap KALE that has been
@@ -21,18 +36,30 @@ impl CustomerTrait for TradingPostContract {
     /// * `customer` - The address of a customer making the trade.
     /// * `vegetable` - The SAC address of the vegetable which should be traded
     ///   against `KALE`.
-    /// * `amount` - The amount of tokens (in stroops) which should be exchanged
-    ///   between the customer and the trading post.
+    /// * `amount` - The amount of tokens (in stroops) the customer is sending
+    ///   to the trading post: `KALE` if buying a vegetable, or the vegetable
+    ///   if buying `KALE`. What the customer receives in return depends on the
+    ///   vegetable's prices and fees (see `quote_buy` and `quote_sell`).
     /// * `buy_kale` - Whether or not the customer should receive `KALE` or the
     ///   other vegetable.
     ///
     /// # Panics
     ///
-    /// * If the trading post is not open for business.
+    /// * If the trading post is not open for business, or it's outside of its
+    ///   opening hours.
     /// * If the desired vegetable token is not in the list of available
-    ///   vegetables.
+    ///   vegetables, or it's out of season.
     /// * If the trading post contract does not have a high enough `KALE`
     ///   balance to send to the customer (if buying `KALE`).
+    /// * If `amount` is too small to receive anything in return.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["trade", customer: Address, vegetable: Address]`
+    /// - data - `[buy_kale: bool, amount_in: i128, amount_out: i128, fee: i128,
+    ///   kale_reserve: i128]`, where `kale_reserve` is the trading post's
+    ///   `KALE` balance after the trade
     fn trade(env: Env, customer: Address, vegetable: Address, amount: i128, buy_kale: bool) {
         // make sure the trading post is open for business
         check_is_open(&env);
@@ -40,42 +67,579 @@ impl CustomerTrait for TradingPostContract {
         // require authorization from the customer
         customer.require_auth();
 
-        // make sure the desired vegetable token is available for trade
-        let vegetables_for_trade = get_vegetables_for_trade(&env);
-        if !vegetables_for_trade.contains(&vegetable) {
-            panic_with_error!(&env, Errors::InvalidVegetable);
+        // make sure the desired vegetable token is available for trade, and
+        // in season
+        check_is_for_trade(&env, &vegetable);
+        check_in_season(&env, &vegetable);
+
+        // work out what the customer gets in return
+        let quote = if buy_kale {
+            quote_sell(&env, &vegetable, amount)
+        } else {
+            quote_buy(&env, &vegetable, amount)
+        };
+        check_quote(&env, &quote);
+
+        execute_trade(&env, &customer, &vegetable, amount, &quote, buy_kale);
+
+        extend_instance_ttl(&env);
+    }
+
+    /// Trade an exact amount between `KALE` and some other, related vegetable,
+    /// so long as the customer receives at least `min_amount_out` in return.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer` - The address of a customer making the trade.
+    /// * `vegetable` - The SAC address of the vegetable which should be traded
+    ///   against `KALE`.
+    /// * `amount_in` - The amount of tokens (in stroops) the customer is
+    ///   sending to the trading post.
+    /// * `min_amount_out` - The least the customer is willing to receive in
+    ///   return (in stroops).
+    /// * `deadline_ledger` - The last ledger on which the trade can happen.
+    /// * `buy_kale` - Whether or not the customer should receive `KALE` or the
+    ///   other vegetable.
+    ///
+    /// # Panics
+    ///
+    /// * If `deadline_ledger` has already passed.
+    /// * If the customer would receive less than `min_amount_out`.
+    /// * For any of the reasons `trade` would panic.
+    ///
+    /// # Events
+    ///
+    /// Emits the same event as `trade`.
+    fn trade_exact_in(
+        env: Env,
+        customer: Address,
+        vegetable: Address,
+        amount_in: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+        buy_kale: bool,
+    ) {
+        // make sure the trading post is open for business
+        check_is_open(&env);
+        // make sure the customer still wants this trade
+        check_deadline(&env, deadline_ledger);
+
+        // require authorization from the customer
+        customer.require_auth();
+
+        // make sure the desired vegetable token is available for trade, and
+        // in season
+        check_is_for_trade(&env, &vegetable);
+        check_in_season(&env, &vegetable);
+
+        // work out what the customer gets in return
+        let quote = if buy_kale {
+            quote_sell(&env, &vegetable, amount_in)
+        } else {
+            quote_buy(&env, &vegetable, amount_in)
+        };
+        check_quote(&env, &quote);
+
+        if quote.amount_out < min_amount_out {
+            panic_with_error!(&env, Errors::SlippageExceeded);
         }
 
-        // create the token client for `KALE`
-        let kale_address = get_kale_address(&env);
-        let kale_client = token::Client::new(&env, &kale_address);
+        execute_trade(&env, &customer, &vegetable, amount_in, &quote, buy_kale);
 
-        if buy_kale {
-            // the customer is receiving kale and sending a vegetable. so, we
-            // will only need a regular SAC client
-            let vegetable_client = token::Client::new(&env, &vegetable);
+        extend_instance_ttl(&env);
+    }
 
-            // ensure we have enough KALE to facilitate the trade
-            let balance = kale_client.balance(&env.current_contract_address());
-            if balance < amount {
-                panic_with_error!(&env, Errors::NotEnoughKale);
-            }
+    /// Trade between `KALE` and some other, related vegetable so the customer
+    /// receives exactly `amount_out`, so long as it costs no more than
+    /// `max_amount_in`.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer` - The address of a customer making the trade.
+    /// * `vegetable` - The SAC address of the vegetable which should be traded
+    ///   against `KALE`.
+    /// * `amount_out` - The amount of tokens (in stroops) the customer should
+    ///   receive from the trading post.
+    /// * `max_amount_in` - The most the customer is willing to send in return
+    ///   (in stroops).
+    /// * `deadline_ledger` - The last ledger on which the trade can happen.
+    /// * `buy_kale` - Whether or not the customer should receive `KALE` or the
+    ///   other vegetable.
+    ///
+    /// # Panics
+    ///
+    /// * If `deadline_ledger` has already passed.
+    /// * If the customer would need to send more than `max_amount_in`.
+    /// * For any of the reasons `trade` would panic.
+    ///
+    /// # Events
+    ///
+    /// Emits the same event as `trade`.
+    fn trade_exact_out(
+        env: Env,
+        customer: Address,
+        vegetable: Address,
+        amount_out: i128,
+        max_amount_in: i128,
+        deadline_ledger: u32,
+        buy_kale: bool,
+    ) {
+        // make sure the trading post is open for business
+        check_is_open(&env);
+        // make sure the customer still wants this trade
+        check_deadline(&env, deadline_ledger);
+
+        // require authorization from the customer
+        customer.require_auth();
 
-            // burn the vegetable provided by the customer
-            vegetable_client.burn(&customer, &amount);
-            // transfer KALE to the customer
-            kale_client.transfer(&env.current_contract_address(), &customer, &amount);
+        // make sure the desired vegetable token is available for trade, and
+        // in season
+        check_is_for_trade(&env, &vegetable);
+        check_in_season(&env, &vegetable);
+
+        // work out what the customer needs to send
+        let (amount_in, quote) = if buy_kale {
+            quote_sell_exact_out(&env, &vegetable, amount_out)
         } else {
-            // the customer is sending kale and receiving a vegetable. so, we
-            // will `mint`, and we need an admin client
-            let vegetable_client = token::StellarAssetClient::new(&env, &vegetable);
-
-            // transfer the KALE from the customer
-            kale_client.transfer(&customer, &env.current_contract_address(), &amount);
-            // mint same amount of the desired vegetable
-            vegetable_client.mint(&customer, &amount);
+            quote_buy_exact_out(&env, &vegetable, amount_out)
+        };
+
+        if amount_in > max_amount_in {
+            panic_with_error!(&env, Errors::SlippageExceeded);
         }
 
+        execute_trade(&env, &customer, &vegetable, amount_in, &quote, buy_kale);
+
         extend_instance_ttl(&env);
     }
+
+    /// Trade one vegetable directly for another, as if selling it for `KALE`
+    /// and buying the other with that `KALE`, in a single call. The customer's
+    /// vegetable is burned (or added to its pool) and the other is minted (or
+    /// taken from its pool), so no `KALE` changes hands. The fees are taken in
+    /// `to_vegetable`, out of what the customer receives.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer` - The address of a customer making the swap.
+    /// * `from_vegetable` - The SAC address of the vegetable the customer is
+    ///   sending.
+    /// * `to_vegetable` - The SAC address of the vegetable the customer should
+    ///   receive.
+    /// * `amount` - The amount of `from_vegetable` (in stroops) the customer is
+    ///   sending.
+    /// * `min_out` - The least of `to_vegetable` the customer is willing to
+    ///   receive in return (in stroops).
+    ///
+    /// # Panics
+    ///
+    /// * If the trading post is not open for business, or it's outside of its
+    ///   opening hours.
+    /// * If either vegetable is not in the list of available vegetables, or
+    ///   they are the same vegetable.
+    /// * If either vegetable is out of season.
+    /// * If `amount` is too small to receive anything in return.
+    /// * If the customer would receive less than `min_out`.
+    /// * If `to_vegetable` is reserve-backed and the trading post doesn't
+    ///   hold enough of it.
+    /// * If `to_vegetable` is pooled but `from_vegetable` isn't, and the
+    ///   trading post doesn't have enough `KALE` of its own to add to the pool.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["swap", customer: Address, from_vegetable: Address,
+    ///   to_vegetable: Address]`
+    /// - data - `[amount_in: i128, amount_out: i128, fee: i128, kale_reserve:
+    ///   i128]`, where `amount_out` is after fees and `fee` is the amount of
+    ///   `to_vegetable` charged for selling and buying
+    fn swap(
+        env: Env,
+        customer: Address,
+        from_vegetable: Address,
+        to_vegetable: Address,
+        amount: i128,
+        min_out: i128,
+    ) {
+        let path = vec![&env, from_vegetable, to_vegetable];
+        execute_swap(&env, &customer, &path, amount, min_out);
+
+        extend_instance_ttl(&env);
+    }
+
+    /// Swap a vegetable through a chain of others in a single call, each hop
+    /// priced as a `swap`. Only the first vegetable is taken and the last
+    /// handed out, the ones in between never change hands. Pools along the way
+    /// still have their reserves moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer` - The address of a customer making the swap.
+    /// * `path` - The SAC addresses of the vegetables to swap through, starting
+    ///   with the one the customer is sending and ending with the one they
+    ///   should receive.
+    /// * `amount` - The amount of the first vegetable (in stroops) the customer
+    ///   is sending.
+    /// * `min_out` - The least of the last vegetable the customer is willing
+    ///   to receive in return (in stroops).
+    ///
+    /// # Panics
+    ///
+    /// * If `path` has fewer than two vegetables, or any hop swaps a vegetable
+    ///   for itself.
+    /// * For any of the reasons `swap` would panic.
+    ///
+    /// # Events
+    ///
+    /// Emits the same event as `swap`, from the first vegetable to the last,
+    /// with `fee` totalled over every hop.
+    fn swap_path(env: Env, customer: Address, path: Vec<Address>, amount: i128, min_out: i128) {
+        execute_swap(&env, &customer, &path, amount, min_out);
+
+        extend_instance_ttl(&env);
+    }
+
+    /// The prices the trading post charges and pays for a vegetable, in basis
+    /// points of `KALE` per unit of vegetable. Vegetables in `Pool` mode are
+    /// priced by their pool instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    fn prices(env: Env, vegetable: Address) -> ExchangeRates {
+        get_exchange_rates(&env, &vegetable)
+    }
+
+    /// Whether the trading post mints and burns a vegetable at its set prices,
+    /// or trades it from a pool of liquidity.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    fn trade_mode(env: Env, vegetable: Address) -> TradeMode {
+        get_trade_mode(&env, &vegetable)
+    }
+
+    /// How the trading post issues a vegetable traded in `Mint` mode, and
+    /// takes it back.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The address of the vegetable's token contract.
+    fn vegetable_kind(env: Env, vegetable: Address) -> VegetableKind {
+        get_vegetable_kind(&env, &vegetable)
+    }
+
+    /// When the trading post trades: whether it's open, whether it's trading
+    /// right now, its opening hours, and the seasons of any vegetables which
+    /// aren't in season all year round.
+    fn schedule(env: Env) -> Schedule {
+        get_schedule(&env)
+    }
+
+    /// Find out how much of a vegetable `trade` would give the customer in
+    /// return for some `KALE`, after fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable being bought.
+    /// * `kale_amount` - The amount of `KALE` (in stroops) the customer would
+    ///   send.
+    ///
+    /// # Panics
+    ///
+    /// * If the desired vegetable token is not in the list of available
+    ///   vegetables.
+    fn quote_buy(env: Env, vegetable: Address, kale_amount: i128) -> i128 {
+        check_is_for_trade(&env, &vegetable);
+
+        quote_buy(&env, &vegetable, kale_amount).amount_out
+    }
+
+    /// Find out how much `KALE` `trade` would give the customer in return for
+    /// some of a vegetable, after fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable being sold.
+    /// * `vegetable_amount` - The amount of the vegetable (in stroops) the
+    ///   customer would send.
+    ///
+    /// # Panics
+    ///
+    /// * If the vegetable token is not in the list of available vegetables.
+    fn quote_sell(env: Env, vegetable: Address, vegetable_amount: i128) -> i128 {
+        check_is_for_trade(&env, &vegetable);
+
+        quote_sell(&env, &vegetable, vegetable_amount).amount_out
+    }
+
+    /// The fees (in basis points) charged on trades of a vegetable, and where
+    /// they go.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    fn fee_info(env: Env, vegetable: Address) -> FeeInfo {
+        let fees = get_fees(&env, &vegetable);
+
+        FeeInfo {
+            buy_fee: fees.buy_fee,
+            sell_fee: fees.sell_fee,
+            destination: get_fee_destination(&env),
+        }
+    }
+
+    /// The limits on how much of a vegetable the trading post will mint, and
+    /// hand out however it's issued.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    fn limits(env: Env, vegetable: Address) -> Limits {
+        get_limits(&env, &vegetable)
+    }
+
+    /// The totals of a vegetable the trading post has minted and burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    fn inventory(env: Env, vegetable: Address) -> Inventory {
+        get_inventory(&env, &vegetable)
+    }
+
+    /// How much more of a vegetable the trading post will mint, and hand out
+    /// in total and to one customer, before reaching each of its limits.
+    /// Volume counts every trade and swap handing the vegetable out, whether
+    /// it's minted, taken from a pool or from the trading post's reserve.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    /// * `customer` - The address of the customer whose own limit to report.
+    fn capacity(env: Env, vegetable: Address, customer: Address) -> Capacity {
+        get_capacity(&env, &vegetable, &customer)
+    }
+}
+
+/// Makes sure `vegetable` is one the trading post has on its shelves
+fn check_is_for_trade(env: &Env, vegetable: &Address) {
+    let vegetables_for_trade = get_vegetables_for_trade(env);
+    if !vegetables_for_trade.contains(vegetable) {
+        panic_with_error!(env, Errors::InvalidVegetable);
+    }
+}
+
+/// Makes sure a trade isn't being submitted after the customer's deadline
+fn check_deadline(env: &Env, deadline_ledger: u32) {
+    if env.ledger().sequence() > deadline_ledger {
+        panic_with_error!(env, Errors::DeadlineExpired);
+    }
+}
+
+/// Prices a swap along `path`, then takes the customer's first vegetable and
+/// gives them the last one. The fees are taken in kind out of the last
+/// vegetable, so no `KALE` leaves the trading post.
+fn execute_swap(env: &Env, customer: &Address, path: &Vec<Address>, amount: i128, min_out: i128) {
+    // make sure the trading post is open for business
+    check_is_open(env);
+
+    // require authorization from the customer
+    customer.require_auth();
+
+    if path.len() < 2 {
+        panic_with_error!(env, Errors::InvalidSwapPath);
+    }
+
+    // create the token client for `KALE`
+    let kale_address = get_kale_address(env);
+    let kale_client = token::Client::new(env, &kale_address);
+
+    // work out what each hop is worth before fees, moving any pooled
+    // vegetables' reserves along the way
+    let mut amount_out = amount;
+    for index in 1..path.len() {
+        let from_vegetable = path.get_unchecked(index - 1);
+        let to_vegetable = path.get_unchecked(index);
+        if from_vegetable == to_vegetable {
+            panic_with_error!(env, Errors::InvalidSwapPath);
+        }
+        check_is_for_trade(env, &from_vegetable);
+        check_is_for_trade(env, &to_vegetable);
+        check_in_season(env, &from_vegetable);
+        check_in_season(env, &to_vegetable);
+
+        let kale_amount = price_sell(env, &from_vegetable, amount_out);
+        let vegetable_amount = price_buy(env, &to_vegetable, kale_amount);
+
+        // a vegetable that isn't pooled brings no KALE with it, so the KALE
+        // going into a pool has to come out of the trading post's own
+        if get_trade_mode(env, &from_vegetable) != TradeMode::Pool
+            && get_trade_mode(env, &to_vegetable) == TradeMode::Pool
+            && get_kale_reserve(env, &kale_client) < kale_amount
+        {
+            panic_with_error!(env, Errors::NotEnoughKale);
+        }
+
+        apply_sell(env, &from_vegetable, amount_out, kale_amount);
+        apply_buy(env, &to_vegetable, kale_amount, vegetable_amount);
+
+        amount_out = vegetable_amount;
+    }
+
+    // then take every hop's fees out of what the customer receives
+    let quote = quote_swap_fee(env, path, amount_out);
+    check_quote(env, &quote);
+    if quote.amount_out < min_out {
+        panic_with_error!(env, Errors::SlippageExceeded);
+    }
+
+    let from_vegetable = path.first_unchecked();
+    let to_vegetable = path.last_unchecked();
+
+    // take the vegetable provided by the customer
+    take_vegetable(env, customer, &from_vegetable, amount);
+    // hand over the desired vegetable
+    give_vegetable(env, customer, &to_vegetable, quote.amount_out);
+    // send the fee on its way
+    collect_vegetable_fee(env, &to_vegetable, quote.fee);
+
+    emit_swap(
+        env,
+        customer,
+        &from_vegetable,
+        &to_vegetable,
+        &amount,
+        &quote.amount_out,
+        &quote.fee,
+        &kale_client.balance(&env.current_contract_address()),
+    );
+}
+
+/// Moves the tokens for an already quoted trade: `amount_in` from the
+/// customer to the trading post, and `quote.amount_out` back to them.
+fn execute_trade(
+    env: &Env,
+    customer: &Address,
+    vegetable: &Address,
+    amount_in: i128,
+    quote: &Quote,
+    buy_kale: bool,
+) {
+    // create the token client for `KALE`
+    let kale_address = get_kale_address(env);
+    let kale_client = token::Client::new(env, &kale_address);
+
+    if buy_kale {
+        // the customer is receiving kale and sending a vegetable
+        if get_trade_mode(env, vegetable) == TradeMode::Pool {
+            // the KALE comes out of the vegetable's pool
+            apply_sell(env, vegetable, amount_in, quote.amount_out + quote.fee);
+        } else if get_kale_reserve(env, &kale_client) < quote.amount_out + quote.fee {
+            // ensure we have enough KALE to facilitate the trade
+            panic_with_error!(env, Errors::NotEnoughKale);
+        }
+
+        // take the vegetable provided by the customer
+        take_vegetable(env, customer, vegetable, amount_in);
+        // transfer KALE to the customer
+        kale_client.transfer(&env.current_contract_address(), customer, &quote.amount_out);
+        // send the fee on its way
+        collect_fee(env, &kale_client, quote.fee);
+    } else {
+        // the customer is sending kale and receiving a vegetable
+        apply_buy(env, vegetable, amount_in - quote.fee, quote.amount_out);
+
+        // transfer the KALE from the customer
+        kale_client.transfer(customer, &env.current_contract_address(), &amount_in);
+        // send the fee on its way
+        collect_fee(env, &kale_client, quote.fee);
+        // hand over the desired vegetable
+        give_vegetable(env, customer, vegetable, quote.amount_out);
+    }
+
+    emit_trade(
+        env,
+        customer,
+        vegetable,
+        &buy_kale,
+        &amount_in,
+        &quote.amount_out,
+        &quote.fee,
+        &kale_client.balance(&env.current_contract_address()),
+    );
+}
+
+/// Takes `amount` of a vegetable from the customer: into the trading post if
+/// it has a pool or is reserve-backed, otherwise burned.
+fn take_vegetable(env: &Env, customer: &Address, vegetable: &Address, amount: i128) {
+    let vegetable_client = token::Client::new(env, vegetable);
+
+    if get_trade_mode(env, vegetable) == TradeMode::Pool
+        || get_vegetable_kind(env, vegetable) == VegetableKind::Reserve
+    {
+        vegetable_client.transfer(customer, &env.current_contract_address(), &amount);
+    } else {
+        vegetable_client.burn(customer, &amount);
+        record_burn(env, vegetable, amount);
+    }
+}
+
+/// Gives `amount` of a vegetable to the customer, counting it towards the
+/// vegetable's volume limits.
+fn give_vegetable(env: &Env, customer: &Address, vegetable: &Address, amount: i128) {
+    issue_vegetable(env, customer, vegetable, amount);
+    record_volume(env, vegetable, customer, amount);
+}
+
+/// Issues `amount` of a vegetable to an address: out of the trading post if
+/// it has a pool or is reserve-backed, otherwise freshly minted with the
+/// client for its kind.
+fn issue_vegetable(env: &Env, to: &Address, vegetable: &Address, amount: i128) {
+    let vegetable_client = token::Client::new(env, vegetable);
+
+    if get_trade_mode(env, vegetable) == TradeMode::Pool {
+        vegetable_client.transfer(&env.current_contract_address(), to, &amount);
+        return;
+    }
+
+    match get_vegetable_kind(env, vegetable) {
+        VegetableKind::StellarAsset => {
+            record_mint(env, vegetable, amount);
+            token::StellarAssetClient::new(env, vegetable).mint(to, &amount);
+        }
+        VegetableKind::Mintable => {
+            record_mint(env, vegetable, amount);
+            MintableClient::new(env, vegetable).mint(to, &amount);
+        }
+        VegetableKind::Reserve => {
+            // ensure we have enough of the vegetable to facilitate the trade
+            if vegetable_client.balance(&env.current_contract_address()) < amount {
+                panic_with_error!(env, Errors::NotEnoughVegetable);
+            }
+
+            vegetable_client.transfer(&env.current_contract_address(), to, &amount);
+        }
+    }
+}
+
+/// Sends a fee taken in `vegetable` to the fee recipient, without counting it
+/// towards the recipient's volume limits. If the fee is to be
+/// burned, a minted vegetable's fee is simply never minted, a reserve-backed
+/// one's stays with the trading post, and a pooled one's (which has already
+/// left its pool) is burned.
+fn collect_vegetable_fee(env: &Env, vegetable: &Address, fee: i128) {
+    if fee == 0 {
+        return;
+    }
+
+    match get_fee_destination(env) {
+        FeeDestination::Burn => {
+            if get_trade_mode(env, vegetable) == TradeMode::Pool {
+                token::Client::new(env, vegetable).burn(&env.current_contract_address(), &fee);
+            }
+        }
+        FeeDestination::Recipient(recipient) => issue_vegetable(env, &recipient, vegetable, fee),
+    }
 }
@@ -0,0 +1,249 @@
+use soroban_sdk::{contractimpl, panic_with_error, token, Address, Env};
+
+use crate::{
+    errors::Errors,
+    events::{emit_add_liquidity, emit_remove_liquidity},
+    pool::sqrt,
+    storage::{
+        extend_instance_ttl, get_kale_address, get_pool, get_pooled_kale, get_shares,
+        get_trade_mode, get_vegetables_for_trade, set_pool, set_pooled_kale, set_shares,
+    },
+    types::{Pool, TradeMode},
+    LiquidityTrait, TradingPostContract, TradingPostContractArgs, TradingPostContractClient,
+};
+
+/// A liquidity provider interface for a trading post contract. Put up `KALE`
+/// and vegetables for the trading post to trade from, in exchange for a share
+/// of the pool.
+#[contractimpl]
+impl LiquidityTrait for TradingPostContract {
+    /// Deposit `KALE` and a vegetable into the vegetable's pool, in exchange
+    /// for shares of it. The first deposit sets the pool's price. After that,
+    /// deposits are taken in proportion to the pool's reserves, using as much
+    /// of the maximums as the price allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The address providing the liquidity.
+    /// * `vegetable` - The SAC address of a vegetable in `Pool` mode.
+    /// * `max_kale` - The most `KALE` (in stroops) to deposit.
+    /// * `max_vegetable` - The most of the vegetable (in stroops) to deposit.
+    /// * `min_shares` - The fewest shares the provider is willing to receive.
+    ///
+    /// # Returns
+    ///
+    /// The number of shares issued to the provider.
+    ///
+    /// # Panics
+    ///
+    /// * If the vegetable is not available for trade, or not in `Pool` mode.
+    /// * If either maximum is not positive.
+    /// * If the deposit is too small to issue any shares.
+    /// * If fewer than `min_shares` would be issued.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["add_liq", provider: Address, vegetable: Address]`
+    /// - data - `[kale_amount: i128, vegetable_amount: i128, shares: i128]`
+    fn add_liquidity(
+        env: Env,
+        provider: Address,
+        vegetable: Address,
+        max_kale: i128,
+        max_vegetable: i128,
+        min_shares: i128,
+    ) -> i128 {
+        // require authorization from the provider
+        provider.require_auth();
+
+        // make sure the vegetable is traded from a pool
+        if !get_vegetables_for_trade(&env).contains(&vegetable) {
+            panic_with_error!(&env, Errors::InvalidVegetable);
+        }
+        if get_trade_mode(&env, &vegetable) != TradeMode::Pool {
+            panic_with_error!(&env, Errors::NotAPool);
+        }
+
+        if max_kale <= 0 || max_vegetable <= 0 {
+            panic_with_error!(&env, Errors::InvalidAmount);
+        }
+
+        // work out how much to take, and how many shares that's worth
+        let mut pool = get_pool(&env, &vegetable);
+        let (kale_amount, vegetable_amount, shares) = if pool.total_shares == 0 {
+            (max_kale, max_vegetable, sqrt(max_kale * max_vegetable))
+        } else {
+            let vegetable_for_kale = max_kale * pool.vegetable_reserve / pool.kale_reserve;
+            let (kale_amount, vegetable_amount) = if vegetable_for_kale <= max_vegetable {
+                (max_kale, vegetable_for_kale)
+            } else {
+                (
+                    max_vegetable * pool.kale_reserve / pool.vegetable_reserve,
+                    max_vegetable,
+                )
+            };
+            let shares = (kale_amount * pool.total_shares / pool.kale_reserve)
+                .min(vegetable_amount * pool.total_shares / pool.vegetable_reserve);
+
+            (kale_amount, vegetable_amount, shares)
+        };
+
+        if shares <= 0 {
+            panic_with_error!(&env, Errors::InsufficientLiquidity);
+        }
+        if shares < min_shares {
+            panic_with_error!(&env, Errors::SlippageExceeded);
+        }
+
+        // take the deposit
+        let kale_client = token::Client::new(&env, &get_kale_address(&env));
+        kale_client.transfer(&provider, &env.current_contract_address(), &kale_amount);
+        let vegetable_client = token::Client::new(&env, &vegetable);
+        vegetable_client.transfer(
+            &provider,
+            &env.current_contract_address(),
+            &vegetable_amount,
+        );
+
+        // set the storage entries
+        pool.kale_reserve += kale_amount;
+        pool.vegetable_reserve += vegetable_amount;
+        pool.total_shares += shares;
+        set_pool(&env, &vegetable, &pool);
+        set_pooled_kale(&env, &(get_pooled_kale(&env) + kale_amount));
+        set_shares(
+            &env,
+            &vegetable,
+            &provider,
+            &(get_shares(&env, &vegetable, &provider) + shares),
+        );
+
+        emit_add_liquidity(
+            &env,
+            &provider,
+            &vegetable,
+            &kale_amount,
+            &vegetable_amount,
+            &shares,
+        );
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+
+        shares
+    }
+
+    /// Redeem shares of a vegetable's pool for their part of its `KALE` and
+    /// vegetable reserves. This works even if the vegetable has since been
+    /// taken off the shelves, or the trading post is closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The address holding the shares.
+    /// * `vegetable` - The SAC address of the pooled vegetable.
+    /// * `shares` - The number of shares to redeem.
+    /// * `min_kale` - The least `KALE` (in stroops) the provider is willing to
+    ///   receive.
+    /// * `min_vegetable` - The least of the vegetable (in stroops) the provider
+    ///   is willing to receive.
+    ///
+    /// # Returns
+    ///
+    /// The amounts of `KALE` and the vegetable sent to the provider.
+    ///
+    /// # Panics
+    ///
+    /// * If `shares` is not positive.
+    /// * If the provider holds fewer than `shares`.
+    /// * If the provider would receive less than `min_kale` or
+    ///   `min_vegetable`.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["rm_liq", provider: Address, vegetable: Address]`
+    /// - data - `[kale_amount: i128, vegetable_amount: i128, shares: i128]`
+    fn remove_liquidity(
+        env: Env,
+        provider: Address,
+        vegetable: Address,
+        shares: i128,
+        min_kale: i128,
+        min_vegetable: i128,
+    ) -> (i128, i128) {
+        // require authorization from the provider
+        provider.require_auth();
+
+        if shares <= 0 {
+            panic_with_error!(&env, Errors::InvalidAmount);
+        }
+
+        let provider_shares = get_shares(&env, &vegetable, &provider);
+        if shares > provider_shares {
+            panic_with_error!(&env, Errors::InsufficientLiquidity);
+        }
+
+        // work out the provider's part of the reserves, rounded down so the
+        // pool keeps any remainder
+        let mut pool = get_pool(&env, &vegetable);
+        let kale_amount = shares * pool.kale_reserve / pool.total_shares;
+        let vegetable_amount = shares * pool.vegetable_reserve / pool.total_shares;
+
+        if kale_amount < min_kale || vegetable_amount < min_vegetable {
+            panic_with_error!(&env, Errors::SlippageExceeded);
+        }
+
+        // set the storage entries
+        pool.kale_reserve -= kale_amount;
+        pool.vegetable_reserve -= vegetable_amount;
+        pool.total_shares -= shares;
+        set_pool(&env, &vegetable, &pool);
+        set_pooled_kale(&env, &(get_pooled_kale(&env) - kale_amount));
+        set_shares(&env, &vegetable, &provider, &(provider_shares - shares));
+
+        // pay out the provider
+        let kale_client = token::Client::new(&env, &get_kale_address(&env));
+        kale_client.transfer(&env.current_contract_address(), &provider, &kale_amount);
+        let vegetable_client = token::Client::new(&env, &vegetable);
+        vegetable_client.transfer(
+            &env.current_contract_address(),
+            &provider,
+            &vegetable_amount,
+        );
+
+        emit_remove_liquidity(
+            &env,
+            &provider,
+            &vegetable,
+            &kale_amount,
+            &vegetable_amount,
+            &shares,
+        );
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+
+        (kale_amount, vegetable_amount)
+    }
+
+    /// The reserves held in a vegetable's pool, and the total shares issued
+    /// against them.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    fn pool(env: Env, vegetable: Address) -> Pool {
+        get_pool(&env, &vegetable)
+    }
+
+    /// The shares of a vegetable's pool held by a liquidity provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `vegetable` - The SAC address of the vegetable.
+    /// * `provider` - The address of the liquidity provider.
+    fn shares(env: Env, vegetable: Address, provider: Address) -> i128 {
+        get_shares(&env, &vegetable, &provider)
+    }
+}
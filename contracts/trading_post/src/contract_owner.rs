@@ -1,6 +1,6 @@
 use crate::{
     storage::{
-        extend_instance_ttl, get_is_open, get_kale_address, get_max_vegetables, get_owner, get_vegetables_for_trade, set_kale_address, set_max_vegetables, set_vegetables_for_trade
+        extend_instance_ttl, get_is_open, get_max_vegetables, get_owner, get_vegetables_for_trade, set_kale_address, set_max_vegetables, set_vegetables_for_trade
     },
     TradingPostContractArgs,
 };
@@ -12,8 +12,22 @@ use soroban_sdk::{
 
 use crate::{
     errors::Errors,
-    storage::{check_is_closed, set_is_open, set_owner},
-    OwnerTrait, TradingPostContract, TradingPostContractClient, DEFAULT_MAX_VEGETABLES_AVAILABLE,
+    events::{
+        emit_accept_admin, emit_add_vegetables, emit_cancel_admin, emit_close, emit_grant_role,
+        emit_open, emit_propose_admin, emit_remove_vegetables, emit_revoke_role, emit_shelf,
+    },
+    limits::max_window_ledgers,
+    schedule::check_season,
+    storage::{
+        check_is_closed, check_role, clear_pending_admin, clear_trade_mode, clear_vegetable_config,
+        clear_vegetable_fees, get_pending_admin, get_pool, get_roles, get_trade_mode, get_vegetable_kind, has_role, set_default_fees,
+        set_exchange_rates, set_fee_destination, set_is_open, set_limits, set_opening_hours,
+        set_owner, set_pending_admin, set_roles, set_season, set_trade_mode, set_vegetable_fees,
+        set_vegetable_kind,
+    },
+    types::{ExchangeRates, FeeDestination, Fees, Limits, Role, Season, TradeMode, VegetableKind},
+    MintableClient, OwnerTrait, TradingPostContract, TradingPostContractClient, BASIS_POINTS,
+    DEFAULT_MAX_VEGETABLES_AVAILABLE,
 };
 
 /// An administrative interface for a trading post contract. Open or close the
@@ -66,11 +80,18 @@ impl OwnerTrait for TradingPostContract {
         extend_instance_ttl(&env);
     }
 
+    /// Same as `upgrade_as`, with the owner as the `caller`.
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let owner = get_owner(&env);
+        Self::upgrade_as(env, owner, new_wasm_hash);
+    }
+
     /// Upgrade the contract's Wasm bytecode. The trading post must be closed
     /// before invoking this function.
     ///
     /// # Arguments
     ///
+    /// * `caller` - The owner, or an address holding the `Upgrader` role.
     /// * `new_wasm_hash` - Hash identifier for the bytecode that should be
     ///   henceforth used by this contract. The bytecode must already be
     ///   installed and present on-chain.
@@ -78,39 +99,204 @@ impl OwnerTrait for TradingPostContract {
     /// # Panics
     ///
     /// * If the trading post is still open.
+    /// * If `caller` is neither the owner nor an upgrader.
     /// * If the Wasm bytecode is not already installed on-chain.
-    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+    fn upgrade_as(env: Env, caller: Address, new_wasm_hash: BytesN<32>) {
         // before we upgrade the trading post, it should be closed. this gives
         // us time for further config stuff, if we need to do some of that.
         check_is_closed(&env);
 
+        // require authorization from the owner, or an upgrader.
+        check_role(&env, &caller, &Role::Upgrader);
+
+        // update the contract Wasm bytecode
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Propose a new owner for the trading post. The proposed address has until
+    /// `expiration_ledger` to accept the role with `accept_admin`, after which
+    /// the proposal lapses. Proposing again replaces any earlier proposal.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner` - The address proposed to become the owner.
+    /// * `expiration_ledger` - The last ledger on which the proposal can be
+    ///   accepted.
+    ///
+    /// # Panics
+    ///
+    /// * If `expiration_ledger` is less than the current ledger number, or
+    ///   further ahead than temporary storage can keep the proposal.
+    fn propose_admin(env: Env, new_owner: Address, expiration_ledger: u32) {
         // require authorization from the owner address.
         let owner = get_owner(&env);
         owner.require_auth();
 
-        // update the contract Wasm bytecode
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        // make sure the proposal won't already be expired, and can be kept
+        // until it does expire
+        if expiration_ledger < env.ledger().sequence()
+            || expiration_ledger - env.ledger().sequence() > env.storage().max_ttl()
+        {
+            panic_with_error!(&env, Errors::InvalidExpirationLedger);
+        }
+
+        // set the storage entry
+        set_pending_admin(&env, &new_owner, &expiration_ledger);
+
+        emit_propose_admin(&env, &owner, &new_owner, &expiration_ledger);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Accept a pending proposal, becoming the owner of the trading post.
+    /// Authorized by the proposed address.
+    ///
+    /// # Panics
+    ///
+    /// * If there is no pending proposal, or it has expired.
+    fn accept_admin(env: Env) {
+        // require authorization from the proposed address.
+        let pending_admin = get_pending_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Errors::NoPendingAdmin));
+        pending_admin.address.require_auth();
+
+        // hand over the keys, and clean up the proposal
+        let old_owner = get_owner(&env);
+        set_owner(&env, &pending_admin.address);
+        clear_pending_admin(&env);
+
+        emit_accept_admin(&env, &old_owner, &pending_admin.address);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Withdraw a pending proposal before it is accepted.
+    ///
+    /// # Panics
+    ///
+    /// * If there is no pending proposal, or it has expired.
+    fn cancel_admin_proposal(env: Env) {
+        // require authorization from the owner address.
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        let pending_admin = get_pending_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Errors::NoPendingAdmin));
+        clear_pending_admin(&env);
+
+        emit_cancel_admin(&env, &owner, &pending_admin.address);
 
         // extend the contract's TTL
         extend_instance_ttl(&env);
     }
 
+    /// Give an address one of the trading post's roles, allowing it to invoke
+    /// the owner functions covered by that role.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The address receiving the role.
+    /// * `role` - The role being granted.
+    ///
+    /// # Panics
+    ///
+    /// * If `account` already holds `role`.
+    fn grant_role(env: Env, account: Address, role: Role) {
+        // require authorization from the owner address.
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        let mut roles = get_roles(&env, &account);
+        if roles.contains(role) {
+            panic_with_error!(&env, Errors::RoleAlreadyGranted);
+        }
+        roles.push_back(role);
+
+        // set the storage entry
+        set_roles(&env, &account, &roles);
+
+        emit_grant_role(&env, &owner, &account, &role);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Take one of the trading post's roles away from an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The address losing the role.
+    /// * `role` - The role being revoked.
+    ///
+    /// # Panics
+    ///
+    /// * If `account` does not hold `role`.
+    fn revoke_role(env: Env, account: Address, role: Role) {
+        // require authorization from the owner address.
+        let owner = get_owner(&env);
+        owner.require_auth();
+
+        let mut roles = get_roles(&env, &account);
+        match roles.first_index_of(role) {
+            Some(r_index) => roles.remove(r_index),
+            None => panic_with_error!(&env, Errors::RoleNotGranted),
+        };
+
+        // set the storage entry
+        set_roles(&env, &account, &roles);
+
+        emit_revoke_role(&env, &owner, &account, &role);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Check whether an address has been granted a role. The owner is not
+    /// reported as holding any roles, though it may use every owner function.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The address to check.
+    /// * `role` - The role to look for.
+    fn has_role(env: Env, account: Address, role: Role) -> bool {
+        has_role(&env, &account, &role)
+    }
+
+    /// Same as `add_vegetables_as`, with the owner as the `caller`.
+    fn add_vegetables(env: Env, vegetables_to_add: Vec<Address>) {
+        let owner = get_owner(&env);
+        Self::add_vegetables_as(env, owner, vegetables_to_add);
+    }
+
     /// Add more vegetables which will be available to trade for using `KALE`
     /// tokens.
     ///
     /// # Arguments
     ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
     /// * `vegetables_to_add` - A vector of vegetable asset contract addresses.
     ///
     /// # Panics
     ///
+    /// * If `caller` is neither the owner nor a shelf manager.
     /// * If a vegetable to be added is already available for trade.
-    /// * If the trading post is open for trading, but the SAC admin of a
-    ///   provided vegetable contract is not the trading post contract address.
-    fn add_vegetables(env: Env, vegetables_to_add: Vec<Address>) {
-        // require authorization from the owner address.
-        let owner = get_owner(&env);
-        owner.require_auth();
+    /// * If the trading post is open for trading, but the admin of a provided
+    ///   `Mint` mode, `StellarAsset` or `Mintable` kind vegetable contract is
+    ///   not the trading post contract address.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["veg_add", caller: Address]`
+    /// - data - `vegetables_to_add: Vec<Address>`
+    fn add_vegetables_as(env: Env, caller: Address, vegetables_to_add: Vec<Address>) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
 
         // retrieve the existing vegetables available
         let mut vegetables_for_trade = get_vegetables_for_trade(&env);
@@ -128,7 +314,7 @@ impl OwnerTrait for TradingPostContract {
         let is_open = get_is_open(&env);
 
         // iterate through all the vegetables, adding or panicking as needed
-        for vegetable in vegetables_to_add {
+        for vegetable in vegetables_to_add.iter() {
             if vegetables_for_trade.contains(&vegetable) {
                 // this vegetable is already available for trade: panic!
                 panic_with_error!(&env, Errors::VegetableAlreadyForTrade);
@@ -136,15 +322,10 @@ impl OwnerTrait for TradingPostContract {
                 // add the vegetable to the vector of available ones
                 vegetables_for_trade.push_back(vegetable.clone());
 
-                // if the trading post is open, make sure it is a SAC admin for
+                // if the trading post is open, make sure it is able to issue
                 // the provided vegetable
                 if is_open {
-                    let vegetable_client = token::StellarAssetClient::new(&env, &vegetable);
-                    let vegetable_admin = vegetable_client.admin();
-
-                    if vegetable_admin != env.current_contract_address() {
-                        panic_with_error!(&env, Errors::ContractNotSacAdmin);
-                    }
+                    check_can_issue(&env, &vegetable);
                 }
             }
         }
@@ -152,31 +333,48 @@ impl OwnerTrait for TradingPostContract {
         // set the storage entry
         set_vegetables_for_trade(&env, &vegetables_for_trade);
 
+        emit_add_vegetables(&env, &caller, &vegetables_to_add);
+
         // extend the contract's TTL
         extend_instance_ttl(&env);
     }
 
+    /// Same as `remove_vegetables_as`, with the owner as the `caller`.
+    fn remove_vegetables(env: Env, vegetables_to_remove: Vec<Address>) {
+        let owner = get_owner(&env);
+        Self::remove_vegetables_as(env, owner, vegetables_to_remove);
+    }
+
     /// Remove vegetables so they will no longer be available to trade for using
-    /// `KALE` tokens.
+    /// `KALE` tokens. Their prices, fees, limits, kind and season are cleared,
+    /// so adding one back starts from the defaults. A pooled vegetable keeps
+    /// its pool and trade mode until its liquidity has all been withdrawn.
     ///
     /// # Arguments
     ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
     /// * `vegetables_to_remove` - A vector of vegetable asset contract
     ///   addresses.
     ///
     /// # Panics
     ///
+    /// * If `caller` is neither the owner nor a shelf manager.
     /// * If a vegetable to be removed is not already available for trade.
-    fn remove_vegetables(env: Env, vegetables_to_remove: Vec<Address>) {
-        // require authorization from the owner address.
-        let owner = get_owner(&env);
-        owner.require_auth();
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["veg_rm", caller: Address]`
+    /// - data - `vegetables_to_remove: Vec<Address>`
+    fn remove_vegetables_as(env: Env, caller: Address, vegetables_to_remove: Vec<Address>) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
 
         // retrieve the current vegetables available for trade
         let mut vegetables_for_trade = get_vegetables_for_trade(&env);
 
         // iterate through all the vegetables, removing or panicking as needed
-        for vegetable in vegetables_to_remove {
+        for vegetable in vegetables_to_remove.iter() {
             if !vegetables_for_trade.contains(&vegetable) {
                 // this vegetable is not even available for trade: panic!
                 panic_with_error!(&env, Errors::VegetableNotForTrade);
@@ -184,32 +382,53 @@ impl OwnerTrait for TradingPostContract {
                 // find the index of the provided vegetable and remove it
                 let v_index = vegetables_for_trade.first_index_of(&vegetable).unwrap();
                 vegetables_for_trade.remove(v_index);
+
+                // clear its config, but don't strand any liquidity
+                clear_vegetable_config(&env, &vegetable);
+                if get_pool(&env, &vegetable).total_shares == 0 {
+                    clear_trade_mode(&env, &vegetable);
+                }
             }
         }
 
         // set the storage entry
         set_vegetables_for_trade(&env, &vegetables_for_trade);
 
+        emit_remove_vegetables(&env, &caller, &vegetables_to_remove);
+
         // extend the contract's TTL
         extend_instance_ttl(&env);
     }
 
+    /// Same as `grow_shelf_space_as`, with the owner as the `caller`.
+    fn grow_shelf_space(env: Env, new_max_vegetables: u32) {
+        let owner = get_owner(&env);
+        Self::grow_shelf_space_as(env, owner, new_max_vegetables);
+    }
+
     /// Increase the maximum number of vegetables available for trade.
     ///
     /// # Arguments
     ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
     /// * `new_max_vegetables` - The new maximum number of vegetables that can
     ///   be available to trade.
     ///
     /// # Panics
     ///
+    /// * If `caller` is neither the owner nor a shelf manager.
     /// * If the existing maximum is greater than the provided new maximum.
     /// * If the existing maximum is equal to the provided new maximum. Why even
     ///   bother then?
-    fn grow_shelf_space(env: Env, new_max_vegetables: u32) {
-        // require authorization from the owner address.
-        let owner = get_owner(&env);
-        owner.require_auth();
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["shelf", caller: Address]`
+    /// - data - `[old_max_vegetables: u32, new_max_vegetables: u32]`
+    fn grow_shelf_space_as(env: Env, caller: Address, new_max_vegetables: u32) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
 
         // make sure the currently configured maximum isn't bigger than the new
         let max_vegetables: u32 = get_max_vegetables(&env);
@@ -220,28 +439,43 @@ impl OwnerTrait for TradingPostContract {
         // set the storage entry
         set_max_vegetables(&env, &new_max_vegetables);
 
+        emit_shelf(&env, &caller, &max_vegetables, &new_max_vegetables);
+
         // extend the contract's TTL
         extend_instance_ttl(&env);
     }
 
+    /// Same as `shrink_shelf_space_as`, with the owner as the `caller`.
+    fn shrink_shelf_space(env: Env, new_max_vegetables: u32) {
+        let owner = get_owner(&env);
+        Self::shrink_shelf_space_as(env, owner, new_max_vegetables);
+    }
+
     /// Decrease the maximum number of vegetables available for trade.
     ///
     /// # Arguments
     ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
     /// * `new_max_vegetables` - The new maximum number of vegetables that can
     ///   be available to trade.
     ///
     /// # Panics
     ///
+    /// * If `caller` is neither the owner nor a shelf manager.
     /// * If the existing maximum is less than the provided new maximum.
     /// * If the existing maximum is equal to the provided new maximum. Why even
     ///   bother then?
     /// * If the number of available vegetables would be bigger than the new
     ///   maximum.
-    fn shrink_shelf_space(env: Env, new_max_vegetables: u32) {
-        // require authorization from the owner address.
-        let owner = get_owner(&env);
-        owner.require_auth();
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["shelf", caller: Address]`
+    /// - data - `[old_max_vegetables: u32, new_max_vegetables: u32]`
+    fn shrink_shelf_space_as(env: Env, caller: Address, new_max_vegetables: u32) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
 
         // make sure the currently configured maximum isn't smaller than the new
         let max_vegetables: u32 = get_max_vegetables(&env);
@@ -259,72 +493,463 @@ impl OwnerTrait for TradingPostContract {
         // set the storage entry
         set_max_vegetables(&env, &new_max_vegetables);
 
+        emit_shelf(&env, &caller, &max_vegetables, &new_max_vegetables);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the prices the trading post charges and pays for a vegetable, in
+    /// basis points of `KALE` per unit of vegetable. A price of 10,000 trades
+    /// 1:1, while a price of 25,000 trades 2.5 `KALE` per vegetable.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
+    /// * `vegetable` - The SAC address of the vegetable being priced.
+    /// * `buy_price` - The `KALE` (in basis points) a customer pays for each
+    ///   unit of `vegetable`.
+    /// * `sell_price` - The `KALE` (in basis points) a customer receives for
+    ///   each unit of `vegetable` sold back to the trading post.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a shelf manager.
+    /// * If the vegetable is not available for trade.
+    /// * If either price is 0, or the sell price is greater than the buy price
+    ///   (which would let customers trade in a loop for free `KALE`).
+    fn set_prices(env: Env, caller: Address, vegetable: Address, buy_price: u32, sell_price: u32) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
+
+        // make sure the vegetable is available for trade
+        let vegetables_for_trade = get_vegetables_for_trade(&env);
+        if !vegetables_for_trade.contains(&vegetable) {
+            panic_with_error!(&env, Errors::VegetableNotForTrade);
+        }
+
+        // make sure the prices make sense
+        if buy_price == 0 || sell_price == 0 || sell_price > buy_price {
+            panic_with_error!(&env, Errors::InvalidPrice);
+        }
+
+        // set the storage entry
+        set_exchange_rates(
+            &env,
+            &vegetable,
+            &ExchangeRates {
+                buy_price,
+                sell_price,
+            },
+        );
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Set limits on how much of a vegetable the trading post will mint, and
+    /// hand out. The mint cap bounds how much can be minted and not yet burned
+    /// back. The volume limits bound how much can be handed out, to all
+    /// customers and to any one customer, in any rolling window of
+    /// `window_ledgers`, whether the vegetable is minted, taken from a pool or
+    /// from the trading post's reserve. A trade counts towards the window for
+    /// at least `window_ledgers`, and at most an eighth longer.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
+    /// * `vegetable` - The SAC address of the vegetable being limited.
+    /// * `limits` - The new limits, replacing any set before. A limit of `None`
+    ///   is switched off.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a shelf manager.
+    /// * If the vegetable is not available for trade.
+    /// * If any limit is negative, or a volume limit is set with a
+    ///   `window_ledgers` of 0.
+    /// * If `window_ledgers` is too long for a customer's volume to be kept
+    ///   in storage for the whole window.
+    fn set_limits(env: Env, caller: Address, vegetable: Address, limits: Limits) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
+
+        // make sure the vegetable is available for trade
+        let vegetables_for_trade = get_vegetables_for_trade(&env);
+        if !vegetables_for_trade.contains(&vegetable) {
+            panic_with_error!(&env, Errors::VegetableNotForTrade);
+        }
+
+        // make sure the limits make sense
+        let has_window_limit =
+            limits.window_volume.is_some() || limits.customer_window_volume.is_some();
+        if [
+            limits.mint_cap,
+            limits.window_volume,
+            limits.customer_window_volume,
+        ]
+        .iter()
+        .any(|limit| limit.is_some_and(|limit| limit < 0))
+            || (has_window_limit && limits.window_ledgers == 0)
+            || limits.window_ledgers > max_window_ledgers(env.storage().max_ttl())
+        {
+            panic_with_error!(&env, Errors::InvalidLimits);
+        }
+
+        // set the storage entry
+        set_limits(&env, &vegetable, &limits);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Choose whether the trading post mints and burns a vegetable at its set
+    /// prices, or trades it from a pool of liquidity put up with
+    /// `add_liquidity`. The trading post must be closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
+    /// * `vegetable` - The SAC address of the vegetable.
+    /// * `mode` - How the vegetable should be traded from now on.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a shelf manager.
+    /// * If the trading post is open.
+    /// * If the vegetable is not available for trade.
+    /// * If the vegetable is leaving `Pool` mode while its pool still holds
+    ///   liquidity.
+    fn set_trade_mode(env: Env, caller: Address, vegetable: Address, mode: TradeMode) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
+
+        // `open` checks the SAC admin for vegetables which are minted
+        check_is_closed(&env);
+
+        // make sure the vegetable is available for trade
+        let vegetables_for_trade = get_vegetables_for_trade(&env);
+        if !vegetables_for_trade.contains(&vegetable) {
+            panic_with_error!(&env, Errors::VegetableNotForTrade);
+        }
+
+        // make sure no liquidity gets stranded
+        if mode != TradeMode::Pool && get_pool(&env, &vegetable).total_shares > 0 {
+            panic_with_error!(&env, Errors::PoolNotEmpty);
+        }
+
+        // set the storage entry
+        set_trade_mode(&env, &vegetable, &mode);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Choose how the trading post issues a vegetable traded in `Mint` mode,
+    /// and takes it back: as a Stellar Asset Contract it is the admin of, as a
+    /// custom token implementing `Mintable`, or by handing out and taking back
+    /// tokens it holds in reserve. The trading post must be closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
+    /// * `vegetable` - The address of the vegetable's token contract.
+    /// * `kind` - The kind of token the vegetable is.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a shelf manager.
+    /// * If the trading post is open.
+    /// * If the vegetable is not available for trade.
+    fn set_vegetable_kind(env: Env, caller: Address, vegetable: Address, kind: VegetableKind) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
+
+        // `open` checks the admin for vegetables which are minted
+        check_is_closed(&env);
+
+        // make sure the vegetable is available for trade
+        let vegetables_for_trade = get_vegetables_for_trade(&env);
+        if !vegetables_for_trade.contains(&vegetable) {
+            panic_with_error!(&env, Errors::VegetableNotForTrade);
+        }
+
+        // set the storage entry
+        set_vegetable_kind(&env, &vegetable, &kind);
+
         // extend the contract's TTL
         extend_instance_ttl(&env);
     }
 
+    /// Set the ledgers the trading post trades in, so it can be opened ahead
+    /// of time and left to start and stop trading on its own. Outside of its
+    /// opening hours an open trading post turns customers away as if it were
+    /// closed. The hours are windows of ledgers, and an empty list drops the
+    /// schedule, leaving the trading post trading whenever it's open.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Operator` role.
+    /// * `opening_hours` - The windows of ledgers to trade in, replacing any
+    ///   set before.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor an operator.
+    /// * If any window ends at or before the ledger it starts.
+    fn set_opening_hours(env: Env, caller: Address, opening_hours: Vec<Season>) {
+        // require authorization from the owner, or an operator.
+        check_role(&env, &caller, &Role::Operator);
+
+        // make sure each window is the right way around
+        for hours in opening_hours.iter() {
+            check_season(&env, &hours);
+        }
+
+        // set the storage entry
+        set_opening_hours(&env, &opening_hours);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Put a vegetable in season for a window of ledgers. Outside of it,
+    /// customers can't trade or swap the vegetable.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `ShelfManager` role.
+    /// * `vegetable` - The address of the vegetable's token contract.
+    /// * `season` - The window of ledgers the vegetable is in season for, or
+    ///   `None` to have it in season all year round.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a shelf manager.
+    /// * If the vegetable is not available for trade.
+    /// * If the season ends at or before the ledger it starts.
+    fn set_season(env: Env, caller: Address, vegetable: Address, season: Option<Season>) {
+        // require authorization from the owner, or a shelf manager.
+        check_role(&env, &caller, &Role::ShelfManager);
+
+        // make sure the vegetable is available for trade
+        let vegetables_for_trade = get_vegetables_for_trade(&env);
+        if !vegetables_for_trade.contains(&vegetable) {
+            panic_with_error!(&env, Errors::VegetableNotForTrade);
+        }
+
+        // make sure the season is the right way around
+        if let Some(season) = &season {
+            check_season(&env, season);
+        }
+
+        // set the storage entry
+        set_season(&env, &vegetable, &season);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the fees charged on trades, in basis points of the `KALE` changing
+    /// hands. Fees are always rounded up.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Treasurer` role.
+    /// * `vegetable` - (optional) The SAC address of a vegetable whose fees
+    ///   should differ from the default. If not provided, the default fees
+    ///   for all vegetables are set instead.
+    /// * `buy_fee` - The fee taken from the `KALE` a customer pays for a
+    ///   vegetable.
+    /// * `sell_fee` - The fee taken from the `KALE` a customer receives when
+    ///   selling a vegetable back to the trading post.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a treasurer.
+    /// * If a vegetable is provided, but is not available for trade.
+    /// * If either fee is more than 10,000 basis points.
+    fn set_fees(
+        env: Env,
+        caller: Address,
+        vegetable: Option<Address>,
+        buy_fee: u32,
+        sell_fee: u32,
+    ) {
+        // require authorization from the owner, or a treasurer.
+        check_role(&env, &caller, &Role::Treasurer);
+
+        // make sure we aren't taking more than the whole trade
+        if buy_fee as i128 > BASIS_POINTS || sell_fee as i128 > BASIS_POINTS {
+            panic_with_error!(&env, Errors::InvalidFee);
+        }
+
+        let fees = Fees { buy_fee, sell_fee };
+
+        // set the storage entry
+        match vegetable {
+            Some(vegetable) => {
+                // make sure the vegetable is available for trade
+                let vegetables_for_trade = get_vegetables_for_trade(&env);
+                if !vegetables_for_trade.contains(&vegetable) {
+                    panic_with_error!(&env, Errors::VegetableNotForTrade);
+                }
+
+                set_vegetable_fees(&env, &vegetable, &fees);
+            }
+            None => set_default_fees(&env, &fees),
+        }
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Remove a vegetable's own fees, so its trades are charged the default
+    /// fees again.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Treasurer` role.
+    /// * `vegetable` - The SAC address of the vegetable.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a treasurer.
+    fn clear_fees(env: Env, caller: Address, vegetable: Address) {
+        // require authorization from the owner, or a treasurer.
+        check_role(&env, &caller, &Role::Treasurer);
+
+        // remove the storage entry
+        clear_vegetable_fees(&env, &vegetable);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Choose where collected fees go: burned, or sent to a recipient address.
+    /// Fees are burned by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Treasurer` role.
+    /// * `destination` - Where future fees should go.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a treasurer.
+    fn set_fee_destination(env: Env, caller: Address, destination: FeeDestination) {
+        // require authorization from the owner, or a treasurer.
+        check_role(&env, &caller, &Role::Treasurer);
+
+        // set the storage entry
+        set_fee_destination(&env, &destination);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Same as `open_as`, with the owner as the `caller`.
+    fn open(env: Env) {
+        let owner = get_owner(&env);
+        Self::open_as(env, owner);
+    }
+
     /// Open the trading post for business. The trading post is closed _by
     /// default_, so this function will need to be invoked before trading can
-    /// begin.
+    /// begin. If opening hours have been set, trading only happens within
+    /// them.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Operator` role.
     ///
     /// # Panics
     ///
+    /// * If `caller` is neither the owner nor an operator.
     /// * If there are no vegetable assets available for trade.
-    /// * If one or more of the available vegetable assets does not have a SAC
-    ///   admin set to this trading post contract's address.
-    fn open(env: Env) {
-        // require authorization from the owner address.
-        let owner = get_owner(&env);
-        owner.require_auth();
+    /// * If one or more of the available `Mint` mode vegetable assets is a
+    ///   `StellarAsset` or `Mintable` kind whose admin is not this trading post
+    ///   contract's address.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["open", caller: Address]`
+    /// - data - `vegetables_for_trade: Vec<Address>`
+    fn open_as(env: Env, caller: Address) {
+        // require authorization from the owner, or an operator.
+        check_role(&env, &caller, &Role::Operator);
 
         // make sure there are actually some vegetables available to trade
         let vegetables_for_trade = get_vegetables_for_trade(&env);
-        if vegetables_for_trade.len() < 1 {
+        if vegetables_for_trade.is_empty() {
             panic_with_error!(&env, Errors::VegetablesRequired);
         }
 
-        // make sure all the vegetables available for trade have the trading
-        // post set as the SAC admin address
+        // make sure the trading post is able to issue all the vegetables
+        // available for trade
         for vegetable in &vegetables_for_trade {
-            let v_client = token::StellarAssetClient::new(&env, &vegetable);
-            let v_admin = v_client.admin();
-
-            if v_admin != env.current_contract_address() {
-                panic_with_error!(&env, Errors::ContractNotSacAdmin);
-            }
+            check_can_issue(&env, &vegetable);
         }
 
         // set the storage entry
         set_is_open(&env, true);
 
+        emit_open(&env, &caller, &vegetables_for_trade);
+
         // extend the contract's TTL
         extend_instance_ttl(&env);
     }
 
-    /// Close the trading post.
+    /// Same as `close_as`, with the owner as the `caller`.
     fn close(env: Env) {
-        // require authorization from the owner address.
         let owner = get_owner(&env);
-        owner.require_auth();
+        Self::close_as(env, owner);
+    }
+
+    /// Close the trading post.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Operator` role.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor an operator.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["close", caller: Address]`
+    /// - data - `()`
+    fn close_as(env: Env, caller: Address) {
+        // require authorization from the owner, or an operator.
+        check_role(&env, &caller, &Role::Operator);
 
         // set the storage entry
         set_is_open(&env, false);
 
+        emit_close(&env, &caller);
+
         // no `extend_instance_ttl` as we're closing up shop
     }
+}
 
-    /// The airdrop changed from 500x to 501x, which left the trading post with
-    /// a small surplus of KALE. Let's burn it to balance out the sKALEs.
-    fn burn_the_extra_kale(env: Env) {
-        // require authorization from the owner address.
-        let owner = get_owner(&env);
-        owner.require_auth();
+/// Makes sure the trading post can issue a vegetable the way it's set up to.
+/// Pooled and reserve-backed vegetables are only ever transferred, while the
+/// others need the trading post as their admin.
+fn check_can_issue(env: &Env, vegetable: &Address) {
+    if get_trade_mode(env, vegetable) == TradeMode::Pool {
+        return;
+    }
 
-        let kale_address = get_kale_address(&env);
-        let kale_client = token::Client::new(&env, &kale_address);
+    let admin = match get_vegetable_kind(env, vegetable) {
+        VegetableKind::StellarAsset => token::StellarAssetClient::new(env, vegetable).admin(),
+        VegetableKind::Mintable => MintableClient::new(env, vegetable).admin(),
+        VegetableKind::Reserve => return,
+    };
 
-        let tp_kale_balance = kale_client.balance(&env.current_contract_address());
-        let amount_to_burn = tp_kale_balance - (4364 * 10_000_000);
-        kale_client.burn(&env.current_contract_address(), &amount_to_burn);
+    if admin != env.current_contract_address() {
+        panic_with_error!(env, Errors::ContractNotSacAdmin);
     }
 }
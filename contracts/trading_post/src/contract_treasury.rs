@@ -0,0 +1,194 @@
+use soroban_sdk::{contractimpl, panic_with_error, token, Address, Env};
+
+use crate::{
+    errors::Errors,
+    events::{emit_burn_kale, emit_deposit_kale, emit_reserve_floor, emit_withdraw_kale},
+    pool::get_kale_reserve,
+    storage::{
+        check_role, extend_instance_ttl, get_kale_address, get_pooled_kale, get_reserve_floor,
+        set_reserve_floor,
+    },
+    types::{Reserves, Role},
+    TradingPostContract, TradingPostContractArgs, TradingPostContractClient, TreasuryTrait,
+};
+
+/// A treasury interface for a trading post contract. Top up, draw down, or
+/// burn the `KALE` the trading post holds to back its trades.
+#[contractimpl]
+impl TreasuryTrait for TradingPostContract {
+    /// Send `KALE` to the trading post, adding to its reserves. Anyone may
+    /// deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The address sending the `KALE`.
+    /// * `amount` - The amount of `KALE` (in stroops) to deposit.
+    ///
+    /// # Panics
+    ///
+    /// * If `amount` is not positive.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["deposit", from: Address]`
+    /// - data - `[amount: i128, kale_reserve: i128]`
+    fn deposit_kale(env: Env, from: Address, amount: i128) {
+        // require authorization from the depositor
+        from.require_auth();
+
+        check_amount(&env, amount);
+
+        let kale_client = token::Client::new(&env, &get_kale_address(&env));
+        kale_client.transfer(&from, &env.current_contract_address(), &amount);
+
+        let kale_reserve = kale_client.balance(&env.current_contract_address());
+        emit_deposit_kale(&env, &from, &amount, &kale_reserve);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Send `KALE` from the trading post's reserves to another address. The
+    /// reserve floor must still be covered afterwards, not counting `KALE`
+    /// held in vegetable pools.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Treasurer` role.
+    /// * `to` - The address receiving the `KALE`.
+    /// * `amount` - The amount of `KALE` (in stroops) to withdraw.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a treasurer.
+    /// * If `amount` is not positive.
+    /// * If the withdrawal would leave less `KALE` than the reserve floor.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["withdraw", caller: Address, to: Address]`
+    /// - data - `[amount: i128, kale_reserve: i128]`
+    fn withdraw_kale(env: Env, caller: Address, to: Address, amount: i128) {
+        // require authorization from the owner, or a treasurer.
+        check_role(&env, &caller, &Role::Treasurer);
+
+        check_amount(&env, amount);
+
+        let kale_client = token::Client::new(&env, &get_kale_address(&env));
+        check_reserve_floor(&env, &kale_client, amount);
+        kale_client.transfer(&env.current_contract_address(), &to, &amount);
+
+        let kale_reserve = kale_client.balance(&env.current_contract_address());
+        emit_withdraw_kale(&env, &caller, &to, &amount, &kale_reserve);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Burn `KALE` from the trading post's reserves. The reserve floor must
+    /// still be covered afterwards, not counting `KALE` held in vegetable
+    /// pools.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Treasurer` role.
+    /// * `amount` - The amount of `KALE` (in stroops) to burn.
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a treasurer.
+    /// * If `amount` is not positive.
+    /// * If the burn would leave less `KALE` than the reserve floor.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["burn", caller: Address]`
+    /// - data - `[amount: i128, kale_reserve: i128]`
+    fn burn_kale(env: Env, caller: Address, amount: i128) {
+        // require authorization from the owner, or a treasurer.
+        check_role(&env, &caller, &Role::Treasurer);
+
+        check_amount(&env, amount);
+
+        let kale_client = token::Client::new(&env, &get_kale_address(&env));
+        check_reserve_floor(&env, &kale_client, amount);
+        kale_client.burn(&env.current_contract_address(), &amount);
+
+        let kale_reserve = kale_client.balance(&env.current_contract_address());
+        emit_burn_kale(&env, &caller, &amount, &kale_reserve);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the least `KALE` the trading post must keep on hand to back
+    /// customers selling vegetables. Withdrawals and burns cannot dip below
+    /// it, though trades can. The floor is 0 by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller` - The owner, or an address holding the `Treasurer` role.
+    /// * `floor` - The new reserve floor (in stroops).
+    ///
+    /// # Panics
+    ///
+    /// * If `caller` is neither the owner nor a treasurer.
+    /// * If `floor` is negative.
+    ///
+    /// # Events
+    ///
+    /// Emits an event with:
+    /// - topics - `["floor", caller: Address]`
+    /// - data - `[old_floor: i128, new_floor: i128]`
+    fn set_reserve_floor(env: Env, caller: Address, floor: i128) {
+        // require authorization from the owner, or a treasurer.
+        check_role(&env, &caller, &Role::Treasurer);
+
+        if floor < 0 {
+            panic_with_error!(&env, Errors::InvalidAmount);
+        }
+
+        let old_floor = get_reserve_floor(&env);
+        set_reserve_floor(&env, &floor);
+
+        emit_reserve_floor(&env, &caller, &old_floor, &floor);
+
+        // extend the contract's TTL
+        extend_instance_ttl(&env);
+    }
+
+    /// The `KALE` the trading post holds, how much of it belongs to vegetable
+    /// pools, the floor it must keep of its own, and how much above the floor
+    /// is available to withdraw or burn.
+    fn reserves(env: Env) -> Reserves {
+        let kale_client = token::Client::new(&env, &get_kale_address(&env));
+        let balance = kale_client.balance(&env.current_contract_address());
+        let pooled = get_pooled_kale(&env);
+        let floor = get_reserve_floor(&env);
+
+        Reserves {
+            balance,
+            pooled,
+            floor,
+            available: (balance - pooled - floor).max(0),
+        }
+    }
+}
+
+/// Makes sure a treasury movement is for a positive amount of `KALE`
+fn check_amount(env: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(env, Errors::InvalidAmount);
+    }
+}
+
+/// Makes sure taking `amount` out of the reserves leaves the floor covered.
+/// `KALE` held in vegetable pools doesn't count towards it
+fn check_reserve_floor(env: &Env, kale_client: &token::Client, amount: i128) {
+    if get_kale_reserve(env, kale_client) - amount < get_reserve_floor(env) {
+        panic_with_error!(env, Errors::BelowReserveFloor);
+    }
+}
@@ -7,7 +7,7 @@ pub enum Errors {
     InvalidVegetable = 1,         // The selected token is not a tradeable vegetable.
     TradingPostClosed = 2,        // The trading post is currently not processing trades.
     VegetablesRequired = 3, // The trading post cannot be set to open if no vegetables are available.
-    ContractNotSacAdmin = 4, // The trading post contract is not set as the SAC admin for a vegetable asset.
+    ContractNotSacAdmin = 4, // The trading post contract is not set as the SAC (or Mintable) admin for a vegetable asset.
     VegetableNotForTrade = 5, // The vegetable to remove is not available for trade.
     VegetableAlreadyForTrade = 6, // The vegetable cannot be removed, it is already available for trade.
     TradingPostOpen = 7,          // The contract cannot be upgraded while the trading post is open.
@@ -15,4 +15,26 @@ pub enum Errors {
     ShelfAlreadyBigger = 9, // The maximum vegetables is already greater than provided expansion.
     ShelfAlreadySmaller = 10, // The maximum vegetables is already smaller than the provided size.
     NotEnoughKale = 11,     // The trading post does not have enough KALE to make the trade.
+    NoPendingAdmin = 12,    // There is no unexpired admin proposal to accept or cancel.
+    InvalidExpirationLedger = 13, // The admin proposal would expire before the current ledger.
+    MissingRole = 14, // The caller is neither the owner nor holds the role required for this action.
+    RoleAlreadyGranted = 15, // The address already holds the role being granted.
+    RoleNotGranted = 16, // The address does not hold the role being revoked.
+    InvalidPrice = 17, // Prices must be positive, and the buy price no lower than the sell price.
+    TradeTooSmall = 18, // The trade amount is too small to receive anything in return.
+    InvalidFee = 19,  // Fees cannot be more than 10,000 basis points (100%).
+    SlippageExceeded = 20, // The trade would give less, or cost more, than the customer allowed.
+    DeadlineExpired = 21, // The trade was submitted after the customer's deadline ledger.
+    InvalidSwapPath = 22, // A swap needs at least two vegetables, and each hop must change vegetable.
+    MintCapExceeded = 23, // The trade would mint more of the vegetable than its cap allows.
+    VolumeLimitExceeded = 24, // The trade would hand out more of the vegetable than its window allows.
+    InvalidLimits = 25,   // Limits cannot be negative, and volume windows must fit in storage.
+    InvalidAmount = 26,   // Treasury amounts must be positive, and the reserve floor not negative.
+    BelowReserveFloor = 27, // The trading post's KALE would drop below its reserve floor.
+    NotAPool = 28,        // The vegetable is not traded from a liquidity pool.
+    PoolNotEmpty = 29,    // The vegetable's pool still holds liquidity.
+    InsufficientLiquidity = 30, // The pool cannot cover the trade, or the liquidity is too small.
+    NotEnoughVegetable = 31, // The trading post does not hold enough of a reserve-backed vegetable.
+    InvalidSchedule = 32, // Opening hours and seasons must each end after they start.
+    OutOfSeason = 33,     // The vegetable is not in season at the current ledger.
 }
@@ -0,0 +1,150 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::types::Role;
+
+pub fn emit_propose_admin(
+    env: &Env,
+    owner: &Address,
+    new_owner: &Address,
+    expiration_ledger: &u32,
+) {
+    let topics = (Symbol::new(env, "propose_admin"), owner);
+    env.events().publish(topics, (new_owner, expiration_ledger));
+}
+
+pub fn emit_accept_admin(env: &Env, old_owner: &Address, new_owner: &Address) {
+    let topics = (Symbol::new(env, "accept_admin"), old_owner);
+    env.events().publish(topics, new_owner);
+}
+
+pub fn emit_cancel_admin(env: &Env, owner: &Address, cancelled_owner: &Address) {
+    let topics = (Symbol::new(env, "cancel_admin"), owner);
+    env.events().publish(topics, cancelled_owner);
+}
+
+pub fn emit_grant_role(env: &Env, owner: &Address, account: &Address, role: &Role) {
+    let topics = (symbol_short!("grant"), owner, account);
+    env.events().publish(topics, *role);
+}
+
+pub fn emit_revoke_role(env: &Env, owner: &Address, account: &Address, role: &Role) {
+    let topics = (symbol_short!("revoke"), owner, account);
+    env.events().publish(topics, *role);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn emit_trade(
+    env: &Env,
+    customer: &Address,
+    vegetable: &Address,
+    buy_kale: &bool,
+    amount_in: &i128,
+    amount_out: &i128,
+    fee: &i128,
+    kale_reserve: &i128,
+) {
+    let topics = (symbol_short!("trade"), customer, vegetable);
+    env.events().publish(
+        topics,
+        (*buy_kale, *amount_in, *amount_out, *fee, *kale_reserve),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn emit_swap(
+    env: &Env,
+    customer: &Address,
+    from_vegetable: &Address,
+    to_vegetable: &Address,
+    amount_in: &i128,
+    amount_out: &i128,
+    fee: &i128,
+    kale_reserve: &i128,
+) {
+    let topics = (
+        symbol_short!("swap"),
+        customer,
+        from_vegetable,
+        to_vegetable,
+    );
+    env.events()
+        .publish(topics, (*amount_in, *amount_out, *fee, *kale_reserve));
+}
+
+pub fn emit_open(env: &Env, caller: &Address, vegetables: &Vec<Address>) {
+    let topics = (symbol_short!("open"), caller);
+    env.events().publish(topics, vegetables.clone());
+}
+
+pub fn emit_close(env: &Env, caller: &Address) {
+    let topics = (symbol_short!("close"), caller);
+    env.events().publish(topics, ());
+}
+
+pub fn emit_add_vegetables(env: &Env, caller: &Address, vegetables: &Vec<Address>) {
+    let topics = (symbol_short!("veg_add"), caller);
+    env.events().publish(topics, vegetables.clone());
+}
+
+pub fn emit_remove_vegetables(env: &Env, caller: &Address, vegetables: &Vec<Address>) {
+    let topics = (symbol_short!("veg_rm"), caller);
+    env.events().publish(topics, vegetables.clone());
+}
+
+pub fn emit_shelf(env: &Env, caller: &Address, old_max_vegetables: &u32, new_max_vegetables: &u32) {
+    let topics = (symbol_short!("shelf"), caller);
+    env.events()
+        .publish(topics, (*old_max_vegetables, *new_max_vegetables));
+}
+
+pub fn emit_deposit_kale(env: &Env, from: &Address, amount: &i128, kale_reserve: &i128) {
+    let topics = (symbol_short!("deposit"), from);
+    env.events().publish(topics, (*amount, *kale_reserve));
+}
+
+pub fn emit_withdraw_kale(
+    env: &Env,
+    caller: &Address,
+    to: &Address,
+    amount: &i128,
+    kale_reserve: &i128,
+) {
+    let topics = (symbol_short!("withdraw"), caller, to);
+    env.events().publish(topics, (*amount, *kale_reserve));
+}
+
+pub fn emit_burn_kale(env: &Env, caller: &Address, amount: &i128, kale_reserve: &i128) {
+    let topics = (symbol_short!("burn"), caller);
+    env.events().publish(topics, (*amount, *kale_reserve));
+}
+
+pub fn emit_reserve_floor(env: &Env, caller: &Address, old_floor: &i128, new_floor: &i128) {
+    let topics = (symbol_short!("floor"), caller);
+    env.events().publish(topics, (*old_floor, *new_floor));
+}
+
+pub fn emit_add_liquidity(
+    env: &Env,
+    provider: &Address,
+    vegetable: &Address,
+    kale_amount: &i128,
+    vegetable_amount: &i128,
+    shares: &i128,
+) {
+    let topics = (symbol_short!("add_liq"), provider, vegetable);
+    env.events()
+        .publish(topics, (*kale_amount, *vegetable_amount, *shares));
+}
+
+pub fn emit_remove_liquidity(
+    env: &Env,
+    provider: &Address,
+    vegetable: &Address,
+    kale_amount: &i128,
+    vegetable_amount: &i128,
+    shares: &i128,
+) {
+    let topics = (symbol_short!("rm_liq"), provider, vegetable);
+    env.events()
+        .publish(topics, (*kale_amount, *vegetable_amount, *shares));
+}
@@ -1,6 +1,10 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractmeta, Address, BytesN, Env, Vec};
+use soroban_sdk::{contract, contractclient, contractmeta, Address, BytesN, Env, Vec};
+use types::{
+    Capacity, ExchangeRates, FeeDestination, FeeInfo, Inventory, Limits, Pool, Reserves, Role,
+    Schedule, Season, TradeMode, VegetableKind,
+};
 
 contractmeta!(
     key = "title",
@@ -16,18 +20,37 @@ contractmeta!(
 );
 
 mod contract_customer;
+mod contract_liquidity;
 mod contract_owner;
+mod contract_treasury;
 mod errors;
+mod events;
+mod limits;
+mod pool;
+mod pricing;
+mod schedule;
 mod storage;
 mod test;
 mod types;
 
 const DEFAULT_MAX_VEGETABLES_AVAILABLE: u32 = 4; // the default maximum number of vegetables available for trading
 const WEEK_OF_LEDGERS: u32 = 60 * 60 * 24 / 5 * 7; // assumes 5 second ledger close times
+const BASIS_POINTS: i128 = 10_000; // 100%, for prices expressed in basis points
 
 #[contract]
 pub struct TradingPostContract;
 
+/// The interface a custom token contract needs for the trading post to issue
+/// it as a `VegetableKind::Mintable` vegetable. Taking it back uses the
+/// standard SEP-41 `burn`.
+#[contractclient(name = "MintableClient")]
+pub trait Mintable {
+    /// The address allowed to mint, which must be the trading post.
+    fn admin(env: Env) -> Address;
+
+    fn mint(env: Env, to: Address, amount: i128);
+}
+
 pub trait OwnerTrait {
     fn __constructor(
         env: Env,
@@ -39,21 +62,150 @@ pub trait OwnerTrait {
 
     fn upgrade(env: Env, new_wasm_hash: BytesN<32>);
 
+    fn upgrade_as(env: Env, caller: Address, new_wasm_hash: BytesN<32>);
+
+    fn propose_admin(env: Env, new_owner: Address, expiration_ledger: u32);
+
+    fn accept_admin(env: Env);
+
+    fn cancel_admin_proposal(env: Env);
+
+    fn grant_role(env: Env, account: Address, role: Role);
+
+    fn revoke_role(env: Env, account: Address, role: Role);
+
+    fn has_role(env: Env, account: Address, role: Role) -> bool;
+
     fn add_vegetables(env: Env, vegetables_to_add: Vec<Address>);
 
+    fn add_vegetables_as(env: Env, caller: Address, vegetables_to_add: Vec<Address>);
+
     fn remove_vegetables(env: Env, vegetables_to_remove: Vec<Address>);
 
+    fn remove_vegetables_as(env: Env, caller: Address, vegetables_to_remove: Vec<Address>);
+
     fn grow_shelf_space(env: Env, new_max_vegetables: u32);
 
+    fn grow_shelf_space_as(env: Env, caller: Address, new_max_vegetables: u32);
+
     fn shrink_shelf_space(env: Env, new_max_vegetables: u32);
 
+    fn shrink_shelf_space_as(env: Env, caller: Address, new_max_vegetables: u32);
+
+    fn set_prices(env: Env, caller: Address, vegetable: Address, buy_price: u32, sell_price: u32);
+
+    fn set_limits(env: Env, caller: Address, vegetable: Address, limits: Limits);
+
+    fn set_trade_mode(env: Env, caller: Address, vegetable: Address, mode: TradeMode);
+
+    fn set_vegetable_kind(env: Env, caller: Address, vegetable: Address, kind: VegetableKind);
+
+    fn set_opening_hours(env: Env, caller: Address, opening_hours: Vec<Season>);
+
+    fn set_season(env: Env, caller: Address, vegetable: Address, season: Option<Season>);
+
+    fn set_fees(env: Env, caller: Address, vegetable: Option<Address>, buy_fee: u32, sell_fee: u32);
+
+    fn clear_fees(env: Env, caller: Address, vegetable: Address);
+
+    fn set_fee_destination(env: Env, caller: Address, destination: FeeDestination);
+
     fn open(env: Env);
 
+    fn open_as(env: Env, caller: Address);
+
     fn close(env: Env);
 
-    fn burn_the_extra_kale(env: Env);
+    fn close_as(env: Env, caller: Address);
+}
+
+pub trait TreasuryTrait {
+    fn deposit_kale(env: Env, from: Address, amount: i128);
+
+    fn withdraw_kale(env: Env, caller: Address, to: Address, amount: i128);
+
+    fn burn_kale(env: Env, caller: Address, amount: i128);
+
+    fn set_reserve_floor(env: Env, caller: Address, floor: i128);
+
+    fn reserves(env: Env) -> Reserves;
+}
+
+pub trait LiquidityTrait {
+    fn add_liquidity(
+        env: Env,
+        provider: Address,
+        vegetable: Address,
+        max_kale: i128,
+        max_vegetable: i128,
+        min_shares: i128,
+    ) -> i128;
+
+    fn remove_liquidity(
+        env: Env,
+        provider: Address,
+        vegetable: Address,
+        shares: i128,
+        min_kale: i128,
+        min_vegetable: i128,
+    ) -> (i128, i128);
+
+    fn pool(env: Env, vegetable: Address) -> Pool;
+
+    fn shares(env: Env, vegetable: Address, provider: Address) -> i128;
 }
 
 pub trait CustomerTrait {
     fn trade(env: Env, customer: Address, vegetable: Address, amount: i128, buy_kale: bool);
+
+    fn trade_exact_in(
+        env: Env,
+        customer: Address,
+        vegetable: Address,
+        amount_in: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+        buy_kale: bool,
+    );
+
+    fn trade_exact_out(
+        env: Env,
+        customer: Address,
+        vegetable: Address,
+        amount_out: i128,
+        max_amount_in: i128,
+        deadline_ledger: u32,
+        buy_kale: bool,
+    );
+
+    fn swap(
+        env: Env,
+        customer: Address,
+        from_vegetable: Address,
+        to_vegetable: Address,
+        amount: i128,
+        min_out: i128,
+    );
+
+    fn swap_path(env: Env, customer: Address, path: Vec<Address>, amount: i128, min_out: i128);
+
+    fn prices(env: Env, vegetable: Address) -> ExchangeRates;
+
+    fn trade_mode(env: Env, vegetable: Address) -> TradeMode;
+
+    fn vegetable_kind(env: Env, vegetable: Address) -> VegetableKind;
+
+    fn schedule(env: Env) -> Schedule;
+
+    fn quote_buy(env: Env, vegetable: Address, kale_amount: i128) -> i128;
+
+    fn quote_sell(env: Env, vegetable: Address, vegetable_amount: i128) -> i128;
+
+    fn fee_info(env: Env, vegetable: Address) -> FeeInfo;
+
+    fn limits(env: Env, vegetable: Address) -> Limits;
+
+    fn inventory(env: Env, vegetable: Address) -> Inventory;
+
+    fn capacity(env: Env, vegetable: Address, customer: Address) -> Capacity;
 }
@@ -0,0 +1,156 @@
+use soroban_sdk::{panic_with_error, vec, Address, Env};
+
+use crate::{
+    errors::Errors,
+    storage::{
+        get_customer_volume, get_inventory, get_limits, get_volume, set_customer_volume,
+        set_inventory, set_volume,
+    },
+    types::{Capacity, Window},
+};
+
+/// How many buckets a volume window is split into. The current bucket and the
+/// `WINDOW_BUCKETS` before it are all counted, so any run of `window_ledgers`
+/// falls inside the volume being limited, and a mint keeps counting for up to
+/// an eighth longer than `window_ledgers`
+const WINDOW_BUCKETS: u32 = 8;
+
+/// How many ledgers each bucket of a volume window lasts
+fn bucket_ledgers(window_ledgers: u32) -> u32 {
+    window_ledgers.div_ceil(WINDOW_BUCKETS)
+}
+
+/// The longest `window_ledgers` whose buckets all fit within `max_ttl`
+pub fn max_window_ledgers(max_ttl: u32) -> u32 {
+    max_ttl / (WINDOW_BUCKETS + 1) * WINDOW_BUCKETS
+}
+
+/// How many ledgers a mint counts towards a volume window for, at most
+pub fn window_span(window_ledgers: u32) -> u32 {
+    bucket_ledgers(window_ledgers) * (WINDOW_BUCKETS + 1)
+}
+
+/// The window a stored one has become by the current ledger, with any buckets
+/// that have rolled out of it dropped and empty ones started in their place
+fn current_window(env: &Env, window: Option<Window>, window_ledgers: u32) -> Window {
+    let bucket = env.ledger().sequence() / bucket_ledgers(window_ledgers);
+    let mut volumes = vec![env];
+
+    if let Some(window) = window {
+        let elapsed = bucket.saturating_sub(window.bucket);
+        if elapsed <= WINDOW_BUCKETS {
+            for _ in 0..elapsed {
+                volumes.push_back(0);
+            }
+            for volume in window
+                .volumes
+                .iter()
+                .take((WINDOW_BUCKETS + 1 - elapsed) as usize)
+            {
+                volumes.push_back(volume);
+            }
+        }
+    }
+    if volumes.is_empty() {
+        volumes.push_back(0);
+    }
+
+    Window { bucket, volumes }
+}
+
+/// The total minted over every bucket still in a window
+fn window_volume(window: &Window) -> i128 {
+    window.volumes.iter().sum()
+}
+
+/// Counts `amount` towards the current bucket of a window
+fn add_to_window(window: &mut Window, amount: i128) {
+    let volume = window.volumes.get_unchecked(0);
+    window.volumes.set(0, volume + amount);
+}
+
+/// How much more of `vegetable` can be minted, and handed out in total and to
+/// `customer`, before reaching each of its limits
+pub fn get_capacity(env: &Env, vegetable: &Address, customer: &Address) -> Capacity {
+    let limits = get_limits(env, vegetable);
+    let inventory = get_inventory(env, vegetable);
+
+    Capacity {
+        mint_cap: limits
+            .mint_cap
+            .map(|cap| (cap - (inventory.minted - inventory.burned)).max(0)),
+        window: limits.window_volume.map(|max| {
+            let window = current_window(env, get_volume(env, vegetable), limits.window_ledgers);
+            (max - window_volume(&window)).max(0)
+        }),
+        customer_window: limits.customer_window_volume.map(|max| {
+            let window = current_window(
+                env,
+                get_customer_volume(env, vegetable, customer),
+                limits.window_ledgers,
+            );
+            (max - window_volume(&window)).max(0)
+        }),
+    }
+}
+
+/// Makes sure minting `amount` of `vegetable` stays within its mint cap, and
+/// counts it towards it
+pub fn record_mint(env: &Env, vegetable: &Address, amount: i128) {
+    let limits = get_limits(env, vegetable);
+    let mut inventory = get_inventory(env, vegetable);
+
+    if limits
+        .mint_cap
+        .is_some_and(|cap| amount > cap - (inventory.minted - inventory.burned))
+    {
+        panic_with_error!(env, Errors::MintCapExceeded);
+    }
+
+    inventory.minted += amount;
+    set_inventory(env, vegetable, &inventory);
+}
+
+/// Makes sure handing `amount` of `vegetable` out to `customer`, however it's
+/// issued, stays within its volume limits, and counts it towards them
+pub fn record_volume(env: &Env, vegetable: &Address, customer: &Address, amount: i128) {
+    let limits = get_limits(env, vegetable);
+    let capacity = get_capacity(env, vegetable, customer);
+
+    if capacity.window.is_some_and(|remaining| amount > remaining)
+        || capacity
+            .customer_window
+            .is_some_and(|remaining| amount > remaining)
+    {
+        panic_with_error!(env, Errors::VolumeLimitExceeded);
+    }
+
+    // volume is only tracked while there's a limit on it
+    if limits.window_volume.is_some() {
+        let mut window = current_window(env, get_volume(env, vegetable), limits.window_ledgers);
+        add_to_window(&mut window, amount);
+        set_volume(env, vegetable, &window);
+    }
+    if limits.customer_window_volume.is_some() {
+        let mut window = current_window(
+            env,
+            get_customer_volume(env, vegetable, customer),
+            limits.window_ledgers,
+        );
+        add_to_window(&mut window, amount);
+        set_customer_volume(
+            env,
+            vegetable,
+            customer,
+            &window,
+            window_span(limits.window_ledgers),
+        );
+    }
+}
+
+/// Counts `amount` of `vegetable` burned, freeing up room under its mint cap
+pub fn record_burn(env: &Env, vegetable: &Address, amount: i128) {
+    let mut inventory = get_inventory(env, vegetable);
+    inventory.burned += amount;
+    set_inventory(env, vegetable, &inventory);
+}
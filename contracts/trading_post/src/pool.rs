@@ -0,0 +1,68 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::{
+    storage::{get_pool, get_pooled_kale, get_trade_mode, set_pool, set_pooled_kale},
+    types::TradeMode,
+};
+
+/// What comes out of a constant-product pool holding `reserve_in` and
+/// `reserve_out` when `amount_in` is put in, rounded down so the pool keeps
+/// any remainder
+pub fn amount_out(reserve_in: i128, reserve_out: i128, amount_in: i128) -> i128 {
+    if amount_in <= 0 || reserve_in <= 0 || reserve_out <= 0 {
+        return 0;
+    }
+
+    reserve_out * amount_in / (reserve_in + amount_in)
+}
+
+/// The `KALE` the trading post holds of its own, leaving out what belongs to
+/// the vegetable pools
+pub fn get_kale_reserve(env: &Env, kale_client: &token::Client) -> i128 {
+    kale_client.balance(&env.current_contract_address()) - get_pooled_kale(env)
+}
+
+/// Moves `KALE` into a vegetable's pool in exchange for some of its
+/// vegetable. Does nothing for vegetables that aren't in `Pool` mode
+pub fn apply_buy(env: &Env, vegetable: &Address, kale_in: i128, vegetable_out: i128) {
+    if get_trade_mode(env, vegetable) != TradeMode::Pool {
+        return;
+    }
+
+    let mut pool = get_pool(env, vegetable);
+    pool.kale_reserve += kale_in;
+    pool.vegetable_reserve -= vegetable_out;
+    set_pool(env, vegetable, &pool);
+    set_pooled_kale(env, &(get_pooled_kale(env) + kale_in));
+}
+
+/// Moves a vegetable into its pool in exchange for some of the pool's
+/// `KALE`. Does nothing for vegetables that aren't in `Pool` mode
+pub fn apply_sell(env: &Env, vegetable: &Address, vegetable_in: i128, kale_out: i128) {
+    if get_trade_mode(env, vegetable) != TradeMode::Pool {
+        return;
+    }
+
+    let mut pool = get_pool(env, vegetable);
+    pool.vegetable_reserve += vegetable_in;
+    pool.kale_reserve -= kale_out;
+    set_pool(env, vegetable, &pool);
+    set_pooled_kale(env, &(get_pooled_kale(env) - kale_out));
+}
+
+/// The largest whole number whose square is no more than `value`
+pub fn sqrt(value: i128) -> i128 {
+    if value < 2 {
+        return value;
+    }
+
+    // newton's method, starting above the root and working down
+    let mut root = value;
+    let mut next = (root + 1) / 2;
+    while next < root {
+        root = next;
+        next = (root + value / root) / 2;
+    }
+
+    root
+}
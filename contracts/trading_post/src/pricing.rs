@@ -0,0 +1,217 @@
+use soroban_sdk::{panic_with_error, token, Address, Env, Vec};
+
+use crate::{
+    errors::Errors,
+    pool::amount_out,
+    storage::{get_exchange_rates, get_fee_destination, get_fees, get_pool, get_trade_mode},
+    types::{FeeDestination, TradeMode},
+    BASIS_POINTS,
+};
+
+/// What a customer receives from a trade, and the fee taken from it. The fee
+/// is in `KALE`, apart from swaps which take it in the vegetable received
+pub struct Quote {
+    pub amount_out: i128,
+    pub fee: i128,
+}
+
+/// The fee on `amount`, rounded up so any remainder goes to the trading post
+fn fee_for(amount: i128, fee: u32) -> i128 {
+    div_ceil(amount * fee as i128, BASIS_POINTS)
+}
+
+/// The amount of `vegetable` that `kale_amount` of `KALE` buys before any
+/// fees, rounded down so any remainder stays with the trading post
+pub fn price_buy(env: &Env, vegetable: &Address, kale_amount: i128) -> i128 {
+    match get_trade_mode(env, vegetable) {
+        TradeMode::Mint => {
+            let rates = get_exchange_rates(env, vegetable);
+            kale_amount * BASIS_POINTS / rates.buy_price as i128
+        }
+        TradeMode::Pool => {
+            let pool = get_pool(env, vegetable);
+            amount_out(pool.kale_reserve, pool.vegetable_reserve, kale_amount)
+        }
+    }
+}
+
+/// The amount of `KALE` that `vegetable_amount` of `vegetable` sells for
+/// before any fees, rounded down so any remainder stays with the trading post
+pub fn price_sell(env: &Env, vegetable: &Address, vegetable_amount: i128) -> i128 {
+    match get_trade_mode(env, vegetable) {
+        TradeMode::Mint => {
+            let rates = get_exchange_rates(env, vegetable);
+            vegetable_amount * rates.sell_price as i128 / BASIS_POINTS
+        }
+        TradeMode::Pool => {
+            let pool = get_pool(env, vegetable);
+            amount_out(pool.vegetable_reserve, pool.kale_reserve, vegetable_amount)
+        }
+    }
+}
+
+/// The amount of `vegetable` a customer receives for `kale_amount` of `KALE`.
+/// The fee comes out of the `KALE` first, and the vegetable is rounded down so
+/// any remainder stays with the trading post
+pub fn quote_buy(env: &Env, vegetable: &Address, kale_amount: i128) -> Quote {
+    let fee = fee_for(kale_amount, get_fees(env, vegetable).buy_fee);
+
+    Quote {
+        amount_out: price_buy(env, vegetable, kale_amount - fee),
+        fee,
+    }
+}
+
+/// The amount of `KALE` a customer receives for `vegetable_amount` of
+/// `vegetable`. The `KALE` is rounded down so any remainder stays with the
+/// trading post, and then the fee comes out of it
+pub fn quote_sell(env: &Env, vegetable: &Address, vegetable_amount: i128) -> Quote {
+    let kale_amount = price_sell(env, vegetable, vegetable_amount);
+    let fee = fee_for(kale_amount, get_fees(env, vegetable).sell_fee);
+
+    Quote {
+        amount_out: kale_amount - fee,
+        fee,
+    }
+}
+
+/// The least `KALE` a customer can send to receive `vegetable_amount` of
+/// `vegetable`, along with the quote for that much `KALE`
+pub fn quote_buy_exact_out(
+    env: &Env,
+    vegetable: &Address,
+    vegetable_amount: i128,
+) -> (i128, Quote) {
+    if vegetable_amount <= 0 {
+        panic_with_error!(env, Errors::TradeTooSmall);
+    }
+
+    let fee = get_fees(env, vegetable).buy_fee as i128;
+    if fee >= BASIS_POINTS {
+        // the fee would eat everything, no amount of KALE is enough
+        panic_with_error!(env, Errors::TradeTooSmall);
+    }
+
+    // undo the pricing, then the fee, rounding up along the way. the result
+    // can still be a stroop short due to rounding, so nudge it up if needed
+    let kale_after_fee = match get_trade_mode(env, vegetable) {
+        TradeMode::Mint => {
+            let rates = get_exchange_rates(env, vegetable);
+            div_ceil(vegetable_amount * rates.buy_price as i128, BASIS_POINTS)
+        }
+        TradeMode::Pool => {
+            let pool = get_pool(env, vegetable);
+            if vegetable_amount >= pool.vegetable_reserve {
+                panic_with_error!(env, Errors::InsufficientLiquidity);
+            }
+            div_ceil(
+                pool.kale_reserve * vegetable_amount,
+                pool.vegetable_reserve - vegetable_amount,
+            )
+        }
+    };
+    let mut kale_amount = div_ceil(kale_after_fee * BASIS_POINTS, BASIS_POINTS - fee);
+    while quote_buy(env, vegetable, kale_amount).amount_out < vegetable_amount {
+        kale_amount += 1;
+    }
+
+    let quote = quote_buy(env, vegetable, kale_amount);
+    (
+        kale_amount,
+        Quote {
+            amount_out: vegetable_amount,
+            fee: quote.fee,
+        },
+    )
+}
+
+/// The least of `vegetable` a customer can send to receive `kale_amount` of
+/// `KALE`, along with the quote for that much vegetable
+pub fn quote_sell_exact_out(env: &Env, vegetable: &Address, kale_amount: i128) -> (i128, Quote) {
+    if kale_amount <= 0 {
+        panic_with_error!(env, Errors::TradeTooSmall);
+    }
+
+    let fee = get_fees(env, vegetable).sell_fee as i128;
+    if fee >= BASIS_POINTS {
+        // the fee would eat everything, no amount of vegetable is enough
+        panic_with_error!(env, Errors::TradeTooSmall);
+    }
+
+    // undo the fee, then the pricing, rounding up along the way. the result
+    // can still be a stroop short due to rounding, so nudge it up if needed
+    let kale_before_fee = div_ceil(kale_amount * BASIS_POINTS, BASIS_POINTS - fee);
+    let mut vegetable_amount = match get_trade_mode(env, vegetable) {
+        TradeMode::Mint => {
+            let rates = get_exchange_rates(env, vegetable);
+            div_ceil(kale_before_fee * BASIS_POINTS, rates.sell_price as i128)
+        }
+        TradeMode::Pool => {
+            let pool = get_pool(env, vegetable);
+            if kale_before_fee >= pool.kale_reserve {
+                panic_with_error!(env, Errors::InsufficientLiquidity);
+            }
+            div_ceil(
+                pool.vegetable_reserve * kale_before_fee,
+                pool.kale_reserve - kale_before_fee,
+            )
+        }
+    };
+    while quote_sell(env, vegetable, vegetable_amount).amount_out < kale_amount {
+        vegetable_amount += 1;
+    }
+
+    let quote = quote_sell(env, vegetable, vegetable_amount);
+    (
+        vegetable_amount,
+        Quote {
+            amount_out: kale_amount,
+            fee: quote.fee,
+        },
+    )
+}
+
+/// What a customer receives from a swap along `path` worth `amount_out` of the
+/// last vegetable before fees. Every hop's sell and buy fees are taken in
+/// turn out of that vegetable, so the fee is in the last vegetable, not `KALE`
+pub fn quote_swap_fee(env: &Env, path: &Vec<Address>, amount_out: i128) -> Quote {
+    let mut remaining = amount_out;
+    for index in 1..path.len() {
+        let from_vegetable = path.get_unchecked(index - 1);
+        let to_vegetable = path.get_unchecked(index);
+        remaining -= fee_for(remaining, get_fees(env, &from_vegetable).sell_fee);
+        remaining -= fee_for(remaining, get_fees(env, &to_vegetable).buy_fee);
+    }
+
+    Quote {
+        amount_out: remaining,
+        fee: amount_out - remaining,
+    }
+}
+
+/// Division for positive numbers, rounding up instead of down
+fn div_ceil(numerator: i128, denominator: i128) -> i128 {
+    (numerator + denominator - 1) / denominator
+}
+
+/// Makes sure a trade actually gives the customer something in return
+pub fn check_quote(env: &Env, quote: &Quote) {
+    if quote.amount_out <= 0 {
+        panic_with_error!(env, Errors::TradeTooSmall);
+    }
+}
+
+/// Sends a `KALE` fee held by the trading post to the fee recipient, or burns
+/// it if there isn't one
+pub fn collect_fee(env: &Env, kale_client: &token::Client, fee: i128) {
+    if fee == 0 {
+        return;
+    }
+
+    match get_fee_destination(env) {
+        FeeDestination::Burn => kale_client.burn(&env.current_contract_address(), &fee),
+        FeeDestination::Recipient(recipient) => {
+            kale_client.transfer(&env.current_contract_address(), &recipient, &fee)
+        }
+    }
+}
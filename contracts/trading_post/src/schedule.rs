@@ -0,0 +1,61 @@
+use soroban_sdk::{panic_with_error, Address, Env, Map};
+
+use crate::{
+    errors::Errors,
+    storage::{get_is_open, get_opening_hours, get_season, get_vegetables_for_trade},
+    types::{Schedule, Season},
+};
+
+/// Whether the current ledger falls within `season`
+fn is_within(env: &Env, season: &Season) -> bool {
+    let sequence = env.ledger().sequence();
+    season.start_ledger <= sequence && sequence < season.end_ledger
+}
+
+/// Whether the trading post has been opened, and the current ledger falls
+/// within its opening hours, if it has any
+pub fn is_trading(env: &Env) -> bool {
+    let opening_hours = get_opening_hours(env);
+
+    get_is_open(env)
+        && (opening_hours.is_empty() || opening_hours.iter().any(|hours| is_within(env, &hours)))
+}
+
+pub fn check_is_open(env: &Env) {
+    if !is_trading(env) {
+        panic_with_error!(env, Errors::TradingPostClosed);
+    }
+}
+
+/// Makes sure `vegetable` is in season at the current ledger, if it has one
+pub fn check_in_season(env: &Env, vegetable: &Address) {
+    if let Some(season) = get_season(env, vegetable) {
+        if !is_within(env, &season) {
+            panic_with_error!(env, Errors::OutOfSeason);
+        }
+    }
+}
+
+/// Makes sure `season` ends after it starts
+pub fn check_season(env: &Env, season: &Season) {
+    if season.end_ledger <= season.start_ledger {
+        panic_with_error!(env, Errors::InvalidSchedule);
+    }
+}
+
+/// The trading post's schedule, as it stands at the current ledger
+pub fn get_schedule(env: &Env) -> Schedule {
+    let mut seasons = Map::new(env);
+    for vegetable in get_vegetables_for_trade(env).iter() {
+        if let Some(season) = get_season(env, &vegetable) {
+            seasons.set(vegetable, season);
+        }
+    }
+
+    Schedule {
+        is_open: get_is_open(env),
+        is_trading: is_trading(env),
+        opening_hours: get_opening_hours(env),
+        seasons,
+    }
+}
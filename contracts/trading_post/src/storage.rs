@@ -1,6 +1,13 @@
 use soroban_sdk::{panic_with_error, Address, Env, Vec};
 
-use crate::{errors::Errors, types::Storage, WEEK_OF_LEDGERS};
+use crate::{
+    errors::Errors,
+    types::{
+        ExchangeRates, FeeDestination, Fees, Inventory, Limits, PendingAdmin, Pool, Role, Season,
+        Storage, TradeMode, VegetableKind, Window,
+    },
+    BASIS_POINTS, WEEK_OF_LEDGERS,
+};
 
 pub fn extend_instance_ttl(env: &Env) {
     let max_ttl = env.storage().max_ttl();
@@ -14,12 +21,6 @@ pub fn get_is_open(env: &Env) -> bool {
     env.storage().instance().get(&Storage::IsOpen).unwrap()
 }
 
-pub fn check_is_open(env: &Env) {
-    if !get_is_open(env) {
-        panic_with_error!(env, Errors::TradingPostClosed);
-    }
-}
-
 pub fn check_is_closed(env: &Env) {
     if get_is_open(env) {
         panic_with_error!(env, Errors::TradingPostOpen);
@@ -38,6 +39,72 @@ pub fn get_owner(env: &Env) -> Address {
     env.storage().instance().get(&Storage::Owner).unwrap()
 }
 
+/// Kept in temporary storage until the proposal expires
+pub fn set_pending_admin(env: &Env, new_owner: &Address, expiration_ledger: &u32) {
+    let pending_admin = PendingAdmin {
+        address: new_owner.clone(),
+        expiration_ledger: *expiration_ledger,
+    };
+
+    env.storage()
+        .temporary()
+        .set(&Storage::PendingAdmin, &pending_admin);
+    extend_pending_admin_ttl(env, expiration_ledger);
+}
+
+/// Treats a proposal whose expiration ledger has passed as if there were none
+pub fn get_pending_admin(env: &Env) -> Option<PendingAdmin> {
+    env.storage()
+        .temporary()
+        .get::<_, PendingAdmin>(&Storage::PendingAdmin)
+        .filter(|pending| pending.expiration_ledger >= env.ledger().sequence())
+}
+
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().temporary().remove(&Storage::PendingAdmin);
+}
+
+pub fn extend_pending_admin_ttl(env: &Env, expiration_ledger: &u32) {
+    let live_for = expiration_ledger
+        .checked_sub(env.ledger().sequence())
+        .unwrap();
+
+    env.storage()
+        .temporary()
+        .extend_ttl(&Storage::PendingAdmin, live_for, live_for);
+}
+
+pub fn set_roles(env: &Env, account: &Address, roles: &Vec<Role>) {
+    let key = Storage::Roles(account.clone());
+
+    if roles.is_empty() {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, roles);
+    }
+}
+
+pub fn get_roles(env: &Env, account: &Address) -> Vec<Role> {
+    env.storage()
+        .instance()
+        .get(&Storage::Roles(account.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn has_role(env: &Env, account: &Address, role: &Role) -> bool {
+    get_roles(env, account).contains(role)
+}
+
+/// Requires authorization from `caller`, and makes sure they are either the
+/// owner or have been granted `role`
+pub fn check_role(env: &Env, caller: &Address, role: &Role) {
+    caller.require_auth();
+
+    if *caller != get_owner(env) && !has_role(env, caller, role) {
+        panic_with_error!(env, Errors::MissingRole);
+    }
+}
+
 pub fn set_kale_address(env: &Env, kale: &Address) {
     env.storage().instance().set(&Storage::KaleAddress, kale);
 }
@@ -68,3 +135,271 @@ pub fn set_vegetables_for_trade(env: &Env, vegetables: &Vec<Address>) {
 pub fn get_vegetables_for_trade(env: &Env) -> Vec<Address> {
     env.storage().instance().get(&Storage::Vegetables).unwrap()
 }
+
+pub fn set_exchange_rates(env: &Env, vegetable: &Address, rates: &ExchangeRates) {
+    env.storage()
+        .instance()
+        .set(&Storage::Rates(vegetable.clone()), rates);
+}
+
+/// Falls back to trading 1:1 with `KALE` if no prices have been set
+pub fn get_exchange_rates(env: &Env, vegetable: &Address) -> ExchangeRates {
+    env.storage()
+        .instance()
+        .get(&Storage::Rates(vegetable.clone()))
+        .unwrap_or(ExchangeRates {
+            buy_price: BASIS_POINTS as u32,
+            sell_price: BASIS_POINTS as u32,
+        })
+}
+
+pub fn set_default_fees(env: &Env, fees: &Fees) {
+    env.storage().instance().set(&Storage::DefaultFees, fees);
+}
+
+/// Falls back to charging no fees if no default has been set
+pub fn get_default_fees(env: &Env) -> Fees {
+    env.storage()
+        .instance()
+        .get(&Storage::DefaultFees)
+        .unwrap_or_default()
+}
+
+pub fn set_vegetable_fees(env: &Env, vegetable: &Address, fees: &Fees) {
+    env.storage()
+        .instance()
+        .set(&Storage::Fees(vegetable.clone()), fees);
+}
+
+pub fn clear_vegetable_fees(env: &Env, vegetable: &Address) {
+    env.storage()
+        .instance()
+        .remove(&Storage::Fees(vegetable.clone()));
+}
+
+/// Falls back to the default fees if none have been set for `vegetable`
+pub fn get_fees(env: &Env, vegetable: &Address) -> Fees {
+    env.storage()
+        .instance()
+        .get(&Storage::Fees(vegetable.clone()))
+        .unwrap_or_else(|| get_default_fees(env))
+}
+
+pub fn set_fee_destination(env: &Env, destination: &FeeDestination) {
+    env.storage()
+        .instance()
+        .set(&Storage::FeeDestination, destination);
+}
+
+/// Falls back to burning fees if no recipient has been set
+pub fn get_fee_destination(env: &Env) -> FeeDestination {
+    env.storage()
+        .instance()
+        .get(&Storage::FeeDestination)
+        .unwrap_or(FeeDestination::Burn)
+}
+
+pub fn set_limits(env: &Env, vegetable: &Address, limits: &Limits) {
+    env.storage()
+        .instance()
+        .set(&Storage::Limits(vegetable.clone()), limits);
+}
+
+/// Falls back to no limits at all if none have been set
+pub fn get_limits(env: &Env, vegetable: &Address) -> Limits {
+    env.storage()
+        .instance()
+        .get(&Storage::Limits(vegetable.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_inventory(env: &Env, vegetable: &Address, inventory: &Inventory) {
+    env.storage()
+        .instance()
+        .set(&Storage::Inventory(vegetable.clone()), inventory);
+}
+
+pub fn get_inventory(env: &Env, vegetable: &Address) -> Inventory {
+    env.storage()
+        .instance()
+        .get(&Storage::Inventory(vegetable.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_volume(env: &Env, vegetable: &Address, window: &Window) {
+    env.storage()
+        .instance()
+        .set(&Storage::Volume(vegetable.clone()), window);
+}
+
+pub fn get_volume(env: &Env, vegetable: &Address) -> Option<Window> {
+    env.storage()
+        .instance()
+        .get(&Storage::Volume(vegetable.clone()))
+}
+
+/// Kept in temporary storage for as long as the window's volume still counts,
+/// after which it would have rolled out anyway
+pub fn set_customer_volume(
+    env: &Env,
+    vegetable: &Address,
+    customer: &Address,
+    window: &Window,
+    window_span: u32,
+) {
+    let key = Storage::CustomerVolume(vegetable.clone(), customer.clone());
+
+    env.storage().temporary().set(&key, window);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, window_span, window_span);
+}
+
+pub fn get_customer_volume(env: &Env, vegetable: &Address, customer: &Address) -> Option<Window> {
+    env.storage().temporary().get(&Storage::CustomerVolume(
+        vegetable.clone(),
+        customer.clone(),
+    ))
+}
+
+pub fn set_reserve_floor(env: &Env, floor: &i128) {
+    env.storage().instance().set(&Storage::ReserveFloor, floor);
+}
+
+/// Falls back to no floor at all if none has been set
+pub fn get_reserve_floor(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&Storage::ReserveFloor)
+        .unwrap_or(0)
+}
+
+pub fn set_trade_mode(env: &Env, vegetable: &Address, mode: &TradeMode) {
+    env.storage()
+        .instance()
+        .set(&Storage::Mode(vegetable.clone()), mode);
+}
+
+pub fn clear_trade_mode(env: &Env, vegetable: &Address) {
+    env.storage()
+        .instance()
+        .remove(&Storage::Mode(vegetable.clone()));
+}
+
+/// Falls back to minting and burning if no mode has been set
+pub fn get_trade_mode(env: &Env, vegetable: &Address) -> TradeMode {
+    env.storage()
+        .instance()
+        .get(&Storage::Mode(vegetable.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_vegetable_kind(env: &Env, vegetable: &Address, kind: &VegetableKind) {
+    env.storage()
+        .instance()
+        .set(&Storage::Kind(vegetable.clone()), kind);
+}
+
+/// Falls back to a Stellar Asset Contract if no kind has been set
+pub fn get_vegetable_kind(env: &Env, vegetable: &Address) -> VegetableKind {
+    env.storage()
+        .instance()
+        .get(&Storage::Kind(vegetable.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_opening_hours(env: &Env, opening_hours: &Vec<Season>) {
+    env.storage()
+        .instance()
+        .set(&Storage::OpeningHours, opening_hours);
+}
+
+/// Falls back to no opening hours, so the trading post trades whenever it's
+/// open
+pub fn get_opening_hours(env: &Env) -> Vec<Season> {
+    env.storage()
+        .instance()
+        .get(&Storage::OpeningHours)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_season(env: &Env, vegetable: &Address, season: &Option<Season>) {
+    let key = Storage::Season(vegetable.clone());
+
+    match season {
+        Some(season) => env.storage().instance().set(&key, season),
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+/// `None` if the vegetable is in season all year round
+pub fn get_season(env: &Env, vegetable: &Address) -> Option<Season> {
+    env.storage()
+        .instance()
+        .get(&Storage::Season(vegetable.clone()))
+}
+
+/// Clears the prices, fees, limits (and the volume counted against them), kind
+/// and season set up for trading `vegetable`. The trade mode is cleared
+/// separately, and the pool and its shares are left alone.
+pub fn clear_vegetable_config(env: &Env, vegetable: &Address) {
+    let instance = env.storage().instance();
+
+    instance.remove(&Storage::Rates(vegetable.clone()));
+    instance.remove(&Storage::Fees(vegetable.clone()));
+    instance.remove(&Storage::Limits(vegetable.clone()));
+    instance.remove(&Storage::Volume(vegetable.clone()));
+    instance.remove(&Storage::Kind(vegetable.clone()));
+    instance.remove(&Storage::Season(vegetable.clone()));
+}
+
+pub fn set_pool(env: &Env, vegetable: &Address, pool: &Pool) {
+    env.storage()
+        .instance()
+        .set(&Storage::Pool(vegetable.clone()), pool);
+}
+
+pub fn get_pool(env: &Env, vegetable: &Address) -> Pool {
+    env.storage()
+        .instance()
+        .get(&Storage::Pool(vegetable.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_pooled_kale(env: &Env, pooled: &i128) {
+    env.storage().instance().set(&Storage::PooledKale, pooled);
+}
+
+/// The `KALE` held across every vegetable pool
+pub fn get_pooled_kale(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&Storage::PooledKale)
+        .unwrap_or(0)
+}
+
+pub fn set_shares(env: &Env, vegetable: &Address, provider: &Address, shares: &i128) {
+    let key = Storage::Shares(vegetable.clone(), provider.clone());
+
+    if *shares == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, shares);
+        extend_shares_ttl(env, &key);
+    }
+}
+
+pub fn get_shares(env: &Env, vegetable: &Address, provider: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&Storage::Shares(vegetable.clone(), provider.clone()))
+        .unwrap_or(0)
+}
+
+pub fn extend_shares_ttl(env: &Env, key: &Storage) {
+    let max_ttl = env.storage().max_ttl();
+
+    env.storage()
+        .persistent()
+        .extend_ttl(key, max_ttl - WEEK_OF_LEDGERS, max_ttl);
+}
@@ -1,7 +1,56 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{bytes, testutils::Address as _, token, vec, Env};
+use soroban_sdk::{
+    bytes, contract, contractimpl, map, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Env, IntoVal, Map, Symbol,
+};
+use storage::{get_owner, get_pending_admin};
+
+/// A bare-bones custom token, the kind of thing a partner farm might issue its
+/// produce from, which the trading post can mint as a `Mintable` vegetable.
+#[contract]
+pub struct FarmToken;
+
+#[contractimpl]
+impl FarmToken {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &admin);
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("admin"))
+            .unwrap()
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        Self::admin(env.clone()).require_auth();
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let balance = Self::balance(env.clone(), from.clone());
+        env.storage().persistent().set(&from, &(balance - amount));
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        Self::burn(env.clone(), from, amount);
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+}
 
 #[test]
 fn test_constructor_with_no_vegetables() {
@@ -529,32 +578,3467 @@ fn test_cannot_trade_for_kale_with_insufficient_balance() {
 }
 
 #[test]
-fn test_burn_the_extra_kale() {
+fn test_admin_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let expiration_ledger = env.ledger().sequence() + 100;
+    trading_post_client.propose_admin(&new_admin, &expiration_ledger);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (Symbol::new(&env, "propose_admin"), admin.clone()).into_val(&env),
+                (new_admin.clone(), expiration_ledger).into_val(&env),
+            ),
+        ]
+    );
+
+    trading_post_client.accept_admin();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (Symbol::new(&env, "accept_admin"), admin.clone()).into_val(&env),
+                new_admin.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(env.auths()[0].0, new_admin);
+
+    env.as_contract(&trading_post_address, || {
+        assert_eq!(get_owner(&env), new_admin);
+        assert_eq!(get_pending_admin(&env), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_cannot_accept_cancelled_admin_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.propose_admin(&new_admin, &(env.ledger().sequence() + 100));
+    trading_post_client.cancel_admin_proposal();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (Symbol::new(&env, "cancel_admin"), admin.clone()).into_val(&env),
+                new_admin.into_val(&env),
+            ),
+        ]
+    );
+
+    trading_post_client.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_cannot_accept_expired_admin_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let expiration_ledger = env.ledger().sequence() + 100;
+    trading_post_client.propose_admin(&Address::generate(&env), &expiration_ledger);
+
+    env.ledger().set_sequence_number(expiration_ledger + 1);
+    trading_post_client.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_cannot_propose_admin_with_expired_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    env.ledger().set_sequence_number(100);
+    trading_post_client.propose_admin(&Address::generate(&env), &99);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_cannot_propose_admin_past_max_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let expiration_ledger = env.ledger().sequence() + env.ledger().get().max_entry_ttl;
+    trading_post_client.propose_admin(&Address::generate(&env), &expiration_ledger);
+}
+
+#[test]
+fn test_operator_can_close_and_reopen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    broccoli_sac_client.set_admin(&trading_post_address);
+
+    trading_post_client.grant_role(&operator, &Role::Operator);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("grant"), admin.clone(), operator.clone()).into_val(&env),
+                Role::Operator.into_val(&env),
+            ),
+        ]
+    );
+    assert!(trading_post_client.has_role(&operator, &Role::Operator));
+    assert!(!trading_post_client.has_role(&operator, &Role::Upgrader));
+
+    trading_post_client.open_as(&operator);
+    trading_post_client.close_as(&operator);
+    assert_eq!(env.auths()[0].0, operator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_operator_cannot_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.grant_role(&operator, &Role::Operator);
+    trading_post_client.upgrade_as(
+        &operator,
+        &env.crypto().sha256(&bytes!(&env, 0xdeadb33f)).to_bytes(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_revoked_shelf_manager_cannot_add_vegetables() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shelf_manager = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.grant_role(&shelf_manager, &Role::ShelfManager);
+    trading_post_client.add_vegetables_as(&shelf_manager, &vec![&env, broccoli.address()]);
+
+    trading_post_client.revoke_role(&shelf_manager, &Role::ShelfManager);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (
+                    symbol_short!("revoke"),
+                    admin.clone(),
+                    shelf_manager.clone()
+                )
+                    .into_val(&env),
+                Role::ShelfManager.into_val(&env),
+            ),
+        ]
+    );
+    assert!(!trading_post_client.has_role(&shelf_manager, &Role::ShelfManager));
+
+    trading_post_client.add_vegetables_as(&shelf_manager, &vec![&env, cabbage.address()]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_cannot_grant_role_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.grant_role(&treasurer, &Role::Treasurer);
+    trading_post_client.grant_role(&treasurer, &Role::Treasurer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_cannot_revoke_role_not_granted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.revoke_role(&treasurer, &Role::Treasurer);
+}
+
+#[test]
+fn test_trade_with_prices() {
     let env = Env::default();
     env.mock_all_auths();
 
     // set up some users
     let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
 
     // set up some assets
     let kale = env.register_stellar_asset_contract_v2(admin.clone());
     let kale_client = token::Client::new(&env, &kale.address());
     let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // broccoli costs 2.5 KALE, and sells back for 2 KALE
+    trading_post_client.set_prices(&admin, &broccoli.address(), &25_000, &20_000);
+    assert_eq!(
+        trading_post_client.prices(&broccoli.address()),
+        ExchangeRates {
+            buy_price: 25_000,
+            sell_price: 20_000,
+        }
+    );
+
+    // 101 stroops of kale buys 40.4 broccoli, rounded down to 40
+    assert_eq!(trading_post_client.quote_buy(&broccoli.address(), &101), 40);
+    kale_sac_client.mint(&farmer, &101i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &101i128, &false);
+    assert_eq!(0i128, kale_client.balance(&farmer));
+    assert_eq!(101i128, kale_client.balance(&trading_post_address));
+    assert_eq!(40i128, broccoli_client.balance(&farmer));
+
+    // 40 stroops of broccoli sells for 80 kale
+    assert_eq!(trading_post_client.quote_sell(&broccoli.address(), &40), 80);
+    trading_post_client.trade(&farmer, &broccoli.address(), &40i128, &true);
+    assert_eq!(80i128, kale_client.balance(&farmer));
+    assert_eq!(21i128, kale_client.balance(&trading_post_address));
+    assert_eq!(0i128, broccoli_client.balance(&farmer));
+}
+
+#[test]
+fn test_quotes_round_down() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    // unpriced vegetables trade 1:1
+    assert_eq!(trading_post_client.quote_buy(&broccoli.address(), &7), 7);
+    assert_eq!(trading_post_client.quote_sell(&broccoli.address(), &7), 7);
+
+    // buy at 3 KALE, sell at 0.3333 KALE
+    trading_post_client.set_prices(&admin, &broccoli.address(), &30_000, &3_333);
+    assert_eq!(trading_post_client.quote_buy(&broccoli.address(), &8), 2);
+    assert_eq!(trading_post_client.quote_sell(&broccoli.address(), &8), 2);
+    assert_eq!(trading_post_client.quote_sell(&broccoli.address(), &3), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_cannot_trade_for_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+    trading_post_client.set_prices(&admin, &broccoli.address(), &30_000, &20_000);
+
+    // 2 stroops of kale isn't enough to buy a single stroop of broccoli
+    kale_sac_client.mint(&farmer, &2i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &2i128, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_cannot_set_sell_price_above_buy_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
 
     let trading_post_address = env.register(
         TradingPostContract,
         (
             &admin,
             &kale.address(),
-            None::<Vec<Address>>,
+            vec![&env, broccoli.address()],
             None::<u32>,
         ),
     );
     let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
 
-    // mint kale directly to the trading post
-    kale_sac_client.mint(&trading_post_address, &(4381456 * 10_000 as i128)); // current balance is 4381.4560000
-    trading_post_client.burn_the_extra_kale();
+    trading_post_client.set_prices(&admin, &broccoli.address(), &10_000, &10_001);
+}
 
-    assert_eq!(kale_client.balance(&trading_post_address), 4364 * 10_000_000); // 4364 left
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_cannot_set_prices_for_unavailable_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_prices(&admin, &broccoli.address(), &10_000, &10_000);
+}
+
+#[test]
+fn test_trade_fees_to_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // 1% to buy, 2.5% to sell, all sent to the recipient
+    trading_post_client.set_fees(&admin, &None, &100, &250);
+    trading_post_client
+        .set_fee_destination(&admin, &FeeDestination::Recipient(fee_recipient.clone()));
+    assert_eq!(
+        trading_post_client.fee_info(&broccoli.address()),
+        FeeInfo {
+            buy_fee: 100,
+            sell_fee: 250,
+            destination: FeeDestination::Recipient(fee_recipient.clone()),
+        }
+    );
+
+    // 1000 kale, less a 10 kale fee, buys 990 broccoli
+    kale_sac_client.mint(&farmer, &1000i128);
+    assert_eq!(
+        trading_post_client.quote_buy(&broccoli.address(), &1000),
+        990
+    );
+    trading_post_client.trade(&farmer, &broccoli.address(), &1000i128, &false);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("trade"), farmer.clone(), broccoli.address()).into_val(&env),
+                (false, 1000i128, 990i128, 10i128, 990i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(990i128, broccoli_client.balance(&farmer));
+    assert_eq!(990i128, kale_client.balance(&trading_post_address));
+    assert_eq!(10i128, kale_client.balance(&fee_recipient));
+
+    // 990 broccoli sells for 990 kale, less a 24.75 kale fee rounded up to 25
+    assert_eq!(
+        trading_post_client.quote_sell(&broccoli.address(), &990),
+        965
+    );
+    trading_post_client.trade(&farmer, &broccoli.address(), &990i128, &true);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("trade"), farmer.clone(), broccoli.address()).into_val(&env),
+                (true, 990i128, 965i128, 25i128, 0i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(965i128, kale_client.balance(&farmer));
+    assert_eq!(0i128, kale_client.balance(&trading_post_address));
+    assert_eq!(35i128, kale_client.balance(&fee_recipient));
+}
+
+#[test]
+fn test_trade_fees_burned() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+    let cabbage_client = token::Client::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // a 1% default, but cabbage is charged 5% to buy
+    trading_post_client.set_fees(&admin, &None, &100, &100);
+    trading_post_client.set_fees(&admin, &Some(cabbage.address()), &500, &100);
+    assert_eq!(
+        trading_post_client.fee_info(&broccoli.address()).buy_fee,
+        100
+    );
+    assert_eq!(
+        trading_post_client.fee_info(&cabbage.address()).buy_fee,
+        500
+    );
+    assert_eq!(
+        trading_post_client.fee_info(&cabbage.address()).destination,
+        FeeDestination::Burn
+    );
+
+    // the 5 kale fee is burned, leaving 95 in the trading post
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &cabbage.address(), &100i128, &false);
+    assert_eq!(95i128, cabbage_client.balance(&farmer));
+    assert_eq!(95i128, kale_client.balance(&trading_post_address));
+
+    // back to the default fee
+    trading_post_client.clear_fees(&admin, &cabbage.address());
+    assert_eq!(
+        trading_post_client.fee_info(&cabbage.address()).buy_fee,
+        100
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_cannot_sell_without_kale_for_the_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+    trading_post_client.set_fees(&admin, &None, &0, &1_000);
+
+    // the post pays out 90, but needs the other 10 on hand for the fee too
+    kale_sac_client.mint(&trading_post_address, &90i128);
+    broccoli_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &true);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+fn test_cannot_set_fee_above_one_hundred_percent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_fees(&admin, &None, &10_001, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_operator_cannot_set_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.grant_role(&operator, &Role::Operator);
+    trading_post_client.set_fees(&operator, &None, &100, &100);
+}
+
+#[test]
+fn test_open_and_close_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    broccoli_sac_client.set_admin(&trading_post_address);
+
+    trading_post_client.open();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("open"), admin.clone()).into_val(&env),
+                vec![&env, broccoli.address()].into_val(&env),
+            ),
+        ]
+    );
+
+    trading_post_client.close();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("close"), admin.clone()).into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_vegetable_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let vegetables = vec![&env, broccoli.address(), cabbage.address()];
+    trading_post_client.add_vegetables(&vegetables);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("veg_add"), admin.clone()).into_val(&env),
+                vegetables.into_val(&env),
+            ),
+        ]
+    );
+
+    trading_post_client.remove_vegetables(&vec![&env, cabbage.address()]);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("veg_rm"), admin.clone()).into_val(&env),
+                vec![&env, cabbage.address()].into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_shelf_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.grow_shelf_space(&6u32);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("shelf"), admin.clone()).into_val(&env),
+                (4u32, 6u32).into_val(&env),
+            ),
+        ]
+    );
+
+    trading_post_client.shrink_shelf_space(&2u32);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("shelf"), admin.clone()).into_val(&env),
+                (6u32, 2u32).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_trade_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // the trading post already has some KALE on hand
+    kale_sac_client.mint(&trading_post_address, &500i128);
+    kale_sac_client.mint(&farmer, &100i128);
+
+    // exchange 100 stroops of kale for broccoli
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("trade"), farmer.clone(), broccoli.address()).into_val(&env),
+                (false, 100i128, 100i128, 0i128, 600i128).into_val(&env),
+            ),
+        ]
+    );
+
+    // and trade 40 of it back
+    trading_post_client.trade(&farmer, &broccoli.address(), &40i128, &true);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("trade"), farmer.clone(), broccoli.address()).into_val(&env),
+                (true, 40i128, 40i128, 0i128, 560i128).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_trade_exact_in() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // broccoli costs 2.5 KALE, and sells back for 2 KALE
+    trading_post_client.set_prices(&admin, &broccoli.address(), &25_000, &20_000);
+    let deadline_ledger = env.ledger().sequence() + 10;
+
+    // 100 stroops of kale buys exactly the 40 broccoli asked for
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade_exact_in(
+        &farmer,
+        &broccoli.address(),
+        &100i128,
+        &40i128,
+        &deadline_ledger,
+        &false,
+    );
+    assert_eq!(0i128, kale_client.balance(&farmer));
+    assert_eq!(40i128, broccoli_client.balance(&farmer));
+
+    // 40 stroops of broccoli sells for 80 kale, more than the 75 asked for
+    trading_post_client.trade_exact_in(
+        &farmer,
+        &broccoli.address(),
+        &40i128,
+        &75i128,
+        &deadline_ledger,
+        &true,
+    );
+    assert_eq!(80i128, kale_client.balance(&farmer));
+    assert_eq!(0i128, broccoli_client.balance(&farmer));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_cannot_trade_exact_in_below_min_amount_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // a 1% fee means 100 kale only buys 99 broccoli
+    trading_post_client.set_fees(&admin, &None, &100, &0);
+
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade_exact_in(
+        &farmer,
+        &broccoli.address(),
+        &100i128,
+        &100i128,
+        &(env.ledger().sequence() + 10),
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_cannot_trade_after_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+    env.ledger().set_sequence_number(100);
+    trading_post_client.trade_exact_in(
+        &farmer,
+        &broccoli.address(),
+        &100i128,
+        &100i128,
+        &99,
+        &false,
+    );
+}
+
+#[test]
+fn test_trade_exact_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // broccoli costs 2.5 KALE and sells back for 2 KALE, with a 1% fee to buy
+    // and a 2.5% fee to sell
+    trading_post_client.set_prices(&admin, &broccoli.address(), &25_000, &20_000);
+    trading_post_client.set_fees(&admin, &None, &100, &250);
+    let deadline_ledger = env.ledger().sequence() + 10;
+
+    // 50 broccoli takes 125 kale after the fee. 126 kale is short once the fee
+    // is rounded up, so the least that works is 127
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade_exact_out(
+        &farmer,
+        &broccoli.address(),
+        &50i128,
+        &127i128,
+        &deadline_ledger,
+        &false,
+    );
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("trade"), farmer.clone(), broccoli.address()).into_val(&env),
+                (false, 127i128, 50i128, 2i128, 125i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(873i128, kale_client.balance(&farmer));
+    assert_eq!(50i128, broccoli_client.balance(&farmer));
+
+    // 80 kale takes 41 broccoli before the fee, but then the fee leaves 79. so
+    // the least that works is 42, and the extra stroop stays with the trading
+    // post
+    trading_post_client.trade_exact_out(
+        &farmer,
+        &broccoli.address(),
+        &80i128,
+        &42i128,
+        &deadline_ledger,
+        &true,
+    );
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("trade"), farmer.clone(), broccoli.address()).into_val(&env),
+                (true, 42i128, 80i128, 3i128, 42i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(953i128, kale_client.balance(&farmer));
+    assert_eq!(42i128, kale_client.balance(&trading_post_address));
+    assert_eq!(8i128, broccoli_client.balance(&farmer));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_cannot_trade_exact_out_above_max_amount_in() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    trading_post_client.set_prices(&admin, &broccoli.address(), &25_000, &20_000);
+    trading_post_client.set_fees(&admin, &None, &100, &250);
+
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade_exact_out(
+        &farmer,
+        &broccoli.address(),
+        &40i128,
+        &101i128,
+        &(env.ledger().sequence() + 10),
+        &false,
+    );
+}
+
+#[test]
+fn test_swap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+    let cabbage_client = token::Client::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // buy some broccoli to swap with
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+
+    // broccoli sells for 2 KALE, and cabbage costs 4 KALE
+    trading_post_client.set_prices(&admin, &broccoli.address(), &25_000, &20_000);
+    trading_post_client.set_prices(&admin, &cabbage.address(), &40_000, &10_000);
+
+    // 50 broccoli is worth 100 kale, which buys 25 cabbage
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &50i128,
+        &25i128,
+    );
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (
+                    symbol_short!("swap"),
+                    farmer.clone(),
+                    broccoli.address(),
+                    cabbage.address()
+                )
+                    .into_val(&env),
+                (50i128, 25i128, 0i128, 100i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(50i128, broccoli_client.balance(&farmer));
+    assert_eq!(25i128, cabbage_client.balance(&farmer));
+
+    // no kale moved in the swap
+    assert_eq!(0i128, kale_client.balance(&farmer));
+    assert_eq!(100i128, kale_client.balance(&trading_post_address));
+}
+
+#[test]
+fn test_swap_path_with_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+    let cabbage_client = token::Client::new(&env, &cabbage.address());
+    let kohlrabi = env.register_stellar_asset_contract_v2(admin.clone());
+    let kohlrabi_sac_client = token::StellarAssetClient::new(&env, &kohlrabi.address());
+    let kohlrabi_client = token::Client::new(&env, &kohlrabi.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![
+                &env,
+                broccoli.address(),
+                cabbage.address(),
+                kohlrabi.address(),
+            ],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    kohlrabi_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // 1% to buy, 2.5% to sell, all burned
+    trading_post_client.set_fees(&admin, &None, &100, &250);
+
+    // 1000 kale, less a 10 kale fee, buys 990 broccoli
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &1000i128, &false);
+    assert_eq!(990i128, kale_client.balance(&trading_post_address));
+
+    // 400 broccoli is worth 400 kohlrabi, less broccoli to cabbage's 10 to
+    // sell and 4 to buy, then cabbage to kohlrabi's 10 to sell and 4 to buy
+    trading_post_client.swap_path(
+        &farmer,
+        &vec![
+            &env,
+            broccoli.address(),
+            cabbage.address(),
+            kohlrabi.address(),
+        ],
+        &400i128,
+        &372i128,
+    );
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (
+                    symbol_short!("swap"),
+                    farmer.clone(),
+                    broccoli.address(),
+                    kohlrabi.address()
+                )
+                    .into_val(&env),
+                (400i128, 372i128, 28i128, 990i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(590i128, broccoli_client.balance(&farmer));
+    assert_eq!(0i128, cabbage_client.balance(&farmer));
+    assert_eq!(372i128, kohlrabi_client.balance(&farmer));
+
+    // the fees were taken in kohlrabi, which was never minted
+    assert_eq!(
+        372i128,
+        trading_post_client.inventory(&kohlrabi.address()).minted
+    );
+    assert_eq!(0i128, kale_client.balance(&farmer));
+    assert_eq!(990i128, kale_client.balance(&trading_post_address));
+}
+
+#[test]
+fn test_swap_fees_without_kale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+    let cabbage_client = token::Client::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // 1% to buy, 2.5% to sell, sent to a recipient
+    trading_post_client.set_fees(&admin, &None, &100, &250);
+    trading_post_client.set_fee_destination(&admin, &FeeDestination::Recipient(recipient.clone()));
+
+    // the farmer got their broccoli elsewhere, so the trading post has no kale
+    broccoli_sac_client.mint(&farmer, &1000i128);
+    assert_eq!(0i128, kale_client.balance(&trading_post_address));
+
+    // 1000 broccoli is worth 1000 cabbage, less 25 to sell and 10 to buy
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &1000i128,
+        &965i128,
+    );
+    assert_eq!(0i128, broccoli_client.balance(&farmer));
+    assert_eq!(965i128, cabbage_client.balance(&farmer));
+
+    // the fee was paid in cabbage, and the trading post's kale is untouched
+    assert_eq!(35i128, cabbage_client.balance(&recipient));
+    assert_eq!(0i128, kale_client.balance(&recipient));
+    assert_eq!(0i128, kale_client.balance(&trading_post_address));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_cannot_swap_below_min_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+
+    // cabbage costs twice as much as broccoli sells for
+    trading_post_client.set_prices(&admin, &cabbage.address(), &20_000, &10_000);
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &100i128,
+        &51i128,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_cannot_swap_vegetable_for_itself() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &broccoli.address(),
+        &100i128,
+        &0i128,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_cannot_swap_path_with_one_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    trading_post_client.swap_path(&farmer, &vec![&env, broccoli.address()], &100i128, &0i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_cannot_swap_untradable_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &100i128,
+        &0i128,
+    );
+}
+
+#[test]
+fn test_mint_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    let limits = Limits {
+        mint_cap: Some(150),
+        ..Default::default()
+    };
+    trading_post_client.set_limits(&admin, &broccoli.address(), &limits);
+    assert_eq!(trading_post_client.limits(&broccoli.address()), limits);
+
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+    assert_eq!(
+        trading_post_client.inventory(&broccoli.address()),
+        Inventory {
+            minted: 100,
+            burned: 0,
+        }
+    );
+    assert_eq!(
+        trading_post_client.capacity(&broccoli.address(), &farmer),
+        Capacity {
+            mint_cap: Some(50),
+            window: None,
+            customer_window: None,
+        }
+    );
+
+    // selling some back makes room under the cap again
+    trading_post_client.trade(&farmer, &broccoli.address(), &40i128, &true);
+    assert_eq!(
+        trading_post_client.inventory(&broccoli.address()),
+        Inventory {
+            minted: 100,
+            burned: 40,
+        }
+    );
+    assert_eq!(
+        trading_post_client
+            .capacity(&broccoli.address(), &farmer)
+            .mint_cap,
+        Some(90)
+    );
+
+    // right up to the cap is fine
+    trading_post_client.trade(&farmer, &broccoli.address(), &90i128, &false);
+    assert_eq!(
+        trading_post_client
+            .capacity(&broccoli.address(), &farmer)
+            .mint_cap,
+        Some(0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+fn test_cannot_mint_past_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    trading_post_client.set_limits(
+        &admin,
+        &cabbage.address(),
+        &Limits {
+            mint_cap: Some(100),
+            ..Default::default()
+        },
+    );
+
+    // swaps count towards the cap too
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &101i128, &false);
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &101i128,
+        &0i128,
+    );
+}
+
+#[test]
+fn test_volume_windows() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let other_farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    // 150 a window for everyone, but only 100 for any one customer
+    trading_post_client.set_limits(
+        &admin,
+        &broccoli.address(),
+        &Limits {
+            mint_cap: None,
+            window_ledgers: 100,
+            window_volume: Some(150),
+            customer_window_volume: Some(100),
+        },
+    );
+
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+    assert_eq!(
+        trading_post_client.capacity(&broccoli.address(), &farmer),
+        Capacity {
+            mint_cap: None,
+            window: Some(50),
+            customer_window: Some(0),
+        }
+    );
+    assert_eq!(
+        trading_post_client.capacity(&broccoli.address(), &other_farmer),
+        Capacity {
+            mint_cap: None,
+            window: Some(50),
+            customer_window: Some(100),
+        }
+    );
+
+    // halfway through the window, someone else takes the rest
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 50);
+    kale_sac_client.mint(&other_farmer, &1000i128);
+    trading_post_client.trade(&other_farmer, &broccoli.address(), &50i128, &false);
+
+    // once the first trade has rolled out of the window, only it is available
+    // again (a window of 100 ledgers is 9 buckets of 13, so 117 at most)
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 67);
+    assert_eq!(
+        trading_post_client.capacity(&broccoli.address(), &farmer),
+        Capacity {
+            mint_cap: None,
+            window: Some(100),
+            customer_window: Some(100),
+        }
+    );
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_cannot_mint_past_window_volume_across_windows() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    trading_post_client.set_limits(
+        &admin,
+        &broccoli.address(),
+        &Limits {
+            window_ledgers: 100,
+            window_volume: Some(150),
+            ..Default::default()
+        },
+    );
+
+    // a little at the start, then the rest at the very end of 100 ledgers
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &10i128, &false);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 99);
+    trading_post_client.trade(&farmer, &broccoli.address(), &140i128, &false);
+
+    // the window rolls on, so the next ledger can't mint it all again
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    trading_post_client.trade(&farmer, &broccoli.address(), &150i128, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_cannot_mint_past_customer_window_volume() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    trading_post_client.set_limits(
+        &admin,
+        &broccoli.address(),
+        &Limits {
+            window_ledgers: 100,
+            customer_window_volume: Some(100),
+            ..Default::default()
+        },
+    );
+
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &60i128, &false);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 99);
+    trading_post_client.trade(&farmer, &broccoli.address(), &60i128, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_cannot_buy_past_window_volume_from_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    trading_post_client.set_limits(
+        &admin,
+        &broccoli.address(),
+        &Limits {
+            window_ledgers: 100,
+            customer_window_volume: Some(500),
+            ..Default::default()
+        },
+    );
+
+    // nothing is minted, but the 363 broccoli from the pool still count
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+    assert_eq!(
+        trading_post_client.capacity(&broccoli.address(), &farmer),
+        Capacity {
+            mint_cap: None,
+            window: None,
+            customer_window: Some(137),
+        }
+    );
+
+    // so another 158 is too much
+    trading_post_client.trade(&farmer, &broccoli.address(), &50i128, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_cannot_mint_past_window_volume() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let other_farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    trading_post_client.set_limits(
+        &admin,
+        &broccoli.address(),
+        &Limits {
+            window_ledgers: 100,
+            window_volume: Some(150),
+            ..Default::default()
+        },
+    );
+
+    kale_sac_client.mint(&farmer, &1000i128);
+    kale_sac_client.mint(&other_farmer, &1000i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &80i128, &false);
+    trading_post_client.trade(&other_farmer, &broccoli.address(), &80i128, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_cannot_set_volume_limit_without_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_limits(
+        &admin,
+        &broccoli.address(),
+        &Limits {
+            window_volume: Some(150),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_cannot_set_window_longer_than_storage_lasts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_limits(
+        &admin,
+        &broccoli.address(),
+        &Limits {
+            window_ledgers: env.ledger().get().max_entry_ttl,
+            customer_window_volume: Some(150),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn test_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.grant_role(&treasurer, &Role::Treasurer);
+
+    // anyone can top up the reserves
+    kale_sac_client.mint(&depositor, &1000i128);
+    trading_post_client.deposit_kale(&depositor, &1000i128);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("deposit"), depositor.clone()).into_val(&env),
+                (1000i128, 1000i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 1000,
+            pooled: 0,
+            floor: 0,
+            available: 1000,
+        }
+    );
+
+    trading_post_client.set_reserve_floor(&treasurer, &600i128);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("floor"), treasurer.clone()).into_val(&env),
+                (0i128, 600i128).into_val(&env),
+            ),
+        ]
+    );
+
+    trading_post_client.withdraw_kale(&treasurer, &recipient, &300i128);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (
+                    symbol_short!("withdraw"),
+                    treasurer.clone(),
+                    recipient.clone()
+                )
+                    .into_val(&env),
+                (300i128, 700i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(300i128, kale_client.balance(&recipient));
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 700,
+            pooled: 0,
+            floor: 600,
+            available: 100,
+        }
+    );
+
+    // burning right down to the floor is fine
+    trading_post_client.burn_kale(&treasurer, &100i128);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (symbol_short!("burn"), treasurer.clone()).into_val(&env),
+                (100i128, 600i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 600,
+            pooled: 0,
+            floor: 600,
+            available: 0,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+fn test_cannot_withdraw_below_reserve_floor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    kale_sac_client.mint(&trading_post_address, &1000i128);
+    trading_post_client.set_reserve_floor(&admin, &600i128);
+    trading_post_client.withdraw_kale(&admin, &recipient, &401i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+fn test_cannot_burn_below_reserve_floor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    kale_sac_client.mint(&trading_post_address, &1000i128);
+    trading_post_client.set_reserve_floor(&admin, &600i128);
+    trading_post_client.burn_kale(&admin, &401i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_cannot_deposit_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.deposit_kale(&depositor, &0i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_operator_cannot_withdraw_kale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (&admin, &kale.address(), None::<Vec<Address>>, None::<u32>),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.grant_role(&operator, &Role::Operator);
+    kale_sac_client.mint(&trading_post_address, &1000i128);
+    trading_post_client.withdraw_kale(&operator, &operator, &100i128);
+}
+
+#[test]
+fn test_pool_trading() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets. the trading post is not the broccoli SAC admin
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    assert_eq!(
+        trading_post_client.trade_mode(&broccoli.address()),
+        TradeMode::Pool
+    );
+    trading_post_client.open();
+
+    // 1000 kale and 4000 broccoli puts the price at 0.25 kale
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    let shares = trading_post_client.add_liquidity(
+        &provider,
+        &broccoli.address(),
+        &1000i128,
+        &4000i128,
+        &0i128,
+    );
+    assert_eq!(shares, 2000);
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (
+                    symbol_short!("add_liq"),
+                    provider.clone(),
+                    broccoli.address()
+                )
+                    .into_val(&env),
+                (1000i128, 4000i128, 2000i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(
+        trading_post_client.shares(&broccoli.address(), &provider),
+        2000
+    );
+
+    // 100 kale buys 4000 * 100 / 1100 = 363.63 broccoli, out of the pool
+    kale_sac_client.mint(&farmer, &100i128);
+    assert_eq!(
+        trading_post_client.quote_buy(&broccoli.address(), &100),
+        363
+    );
+    trading_post_client.trade(&farmer, &broccoli.address(), &100i128, &false);
+    assert_eq!(363i128, broccoli_client.balance(&farmer));
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 1100,
+            vegetable_reserve: 3637,
+            total_shares: 2000,
+        }
+    );
+
+    // and selling it straight back gets 1100 * 363 / 4000 = 99.83 kale
+    trading_post_client.trade(&farmer, &broccoli.address(), &363i128, &true);
+    assert_eq!(99i128, kale_client.balance(&farmer));
+    assert_eq!(0i128, broccoli_client.balance(&farmer));
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 1001,
+            vegetable_reserve: 4000,
+            total_shares: 2000,
+        }
+    );
+
+    // the pooled kale isn't the trading post's to spend
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 1001,
+            pooled: 1001,
+            floor: 0,
+            available: 0,
+        }
+    );
+}
+
+#[test]
+fn test_add_and_remove_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let other_provider = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    // at the pool's price, 500 kale only needs 2000 of the 3000 broccoli
+    kale_sac_client.mint(&other_provider, &500i128);
+    broccoli_sac_client.mint(&other_provider, &3000i128);
+    let shares = trading_post_client.add_liquidity(
+        &other_provider,
+        &broccoli.address(),
+        &500i128,
+        &3000i128,
+        &1000i128,
+    );
+    assert_eq!(shares, 1000);
+    assert_eq!(0i128, kale_client.balance(&other_provider));
+    assert_eq!(1000i128, broccoli_client.balance(&other_provider));
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 1500,
+            vegetable_reserve: 6000,
+            total_shares: 3000,
+        }
+    );
+
+    let (kale_amount, vegetable_amount) = trading_post_client.remove_liquidity(
+        &other_provider,
+        &broccoli.address(),
+        &1000i128,
+        &500i128,
+        &2000i128,
+    );
+    assert_eq!((kale_amount, vegetable_amount), (500, 2000));
+    let events = env.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &env,
+            (
+                trading_post_address.clone(),
+                (
+                    symbol_short!("rm_liq"),
+                    other_provider.clone(),
+                    broccoli.address()
+                )
+                    .into_val(&env),
+                (500i128, 2000i128, 1000i128).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(500i128, kale_client.balance(&other_provider));
+    assert_eq!(3000i128, broccoli_client.balance(&other_provider));
+    assert_eq!(
+        trading_post_client.shares(&broccoli.address(), &other_provider),
+        0
+    );
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 1000,
+            vegetable_reserve: 4000,
+            total_shares: 2000,
+        }
+    );
+}
+
+#[test]
+fn test_swap_from_pool_to_minted_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+    let cabbage_client = token::Client::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    // broccoli comes from a pool, and cabbage is minted
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    // 400 broccoli sells into the pool for 1000 * 400 / 4400 = 90.9 kale,
+    // which mints 90 cabbage
+    broccoli_sac_client.mint(&farmer, &400i128);
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &400i128,
+        &90i128,
+    );
+    assert_eq!(90i128, cabbage_client.balance(&farmer));
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 910,
+            vegetable_reserve: 4400,
+            total_shares: 2000,
+        }
+    );
+
+    // the kale that left the pool now backs the minted cabbage
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 1000,
+            pooled: 910,
+            floor: 0,
+            available: 90,
+        }
+    );
+}
+
+#[test]
+fn test_swap_from_minted_to_pool_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    // broccoli comes from a pool, and cabbage is minted
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    // buying 100 cabbage leaves 100 kale of the trading post's own behind it
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &cabbage.address(), &100i128, &false);
+
+    // 100 cabbage sells for 100 kale, which buys 4000 * 100 / 1100 = 363.6
+    // broccoli from the pool
+    trading_post_client.swap(
+        &farmer,
+        &cabbage.address(),
+        &broccoli.address(),
+        &100i128,
+        &363i128,
+    );
+    assert_eq!(363i128, broccoli_client.balance(&farmer));
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 1100,
+            vegetable_reserve: 3637,
+            total_shares: 2000,
+        }
+    );
+
+    // the kale that backed the cabbage has moved into the pool
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 1100,
+            pooled: 1100,
+            floor: 0,
+            available: 0,
+        }
+    );
+
+    // and the provider can still take all of it out
+    let (kale_amount, vegetable_amount) = trading_post_client.remove_liquidity(
+        &provider,
+        &broccoli.address(),
+        &2000i128,
+        &0i128,
+        &0i128,
+    );
+    assert_eq!((kale_amount, vegetable_amount), (1100, 3637));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_cannot_swap_minted_into_pool_without_kale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    // the farmer got their cabbage elsewhere, so the trading post has no kale
+    // of its own
+    cabbage_sac_client.mint(&farmer, &1000i128);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    trading_post_client.swap(
+        &farmer,
+        &cabbage.address(),
+        &broccoli.address(),
+        &1000i128,
+        &0i128,
+    );
+}
+
+#[test]
+fn test_swap_fees_between_pool_and_minted_vegetables() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+    let cabbage_client = token::Client::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    // broccoli comes from a pool, and cabbage is minted
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    kale_sac_client.mint(&farmer, &1000i128);
+    trading_post_client.trade(&farmer, &cabbage.address(), &1000i128, &false);
+
+    // 1% to buy, 2.5% to sell, all burned
+    trading_post_client.set_fees(&admin, &None, &100, &250);
+
+    // 400 cabbage is worth 4000 * 400 / 1400 = 1142 broccoli, less 29 to sell
+    // and 12 to buy. the fee is burned out of what left the pool
+    trading_post_client.swap(
+        &farmer,
+        &cabbage.address(),
+        &broccoli.address(),
+        &400i128,
+        &1101i128,
+    );
+    assert_eq!(1101i128, broccoli_client.balance(&farmer));
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 1400,
+            vegetable_reserve: 2858,
+            total_shares: 2000,
+        }
+    );
+    assert_eq!(2858i128, broccoli_client.balance(&trading_post_address));
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 2000,
+            pooled: 1400,
+            floor: 0,
+            available: 600,
+        }
+    );
+
+    // and back again: 1101 broccoli sells into the pool for
+    // 1400 * 1101 / 3959 = 389 kale, which is worth 389 cabbage, less 10 to
+    // sell and 4 to buy. the fee is never minted
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &1101i128,
+        &375i128,
+    );
+    assert_eq!(975i128, cabbage_client.balance(&farmer));
+    assert_eq!(
+        trading_post_client.inventory(&cabbage.address()),
+        Inventory {
+            minted: 1375,
+            burned: 400,
+        }
+    );
+    assert_eq!(
+        trading_post_client.pool(&broccoli.address()),
+        Pool {
+            kale_reserve: 1011,
+            vegetable_reserve: 3959,
+            total_shares: 2000,
+        }
+    );
+    assert_eq!(
+        trading_post_client.reserves(),
+        Reserves {
+            balance: 2000,
+            pooled: 1011,
+            floor: 0,
+            available: 989,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+fn test_cannot_buy_whole_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    kale_sac_client.mint(&farmer, &1_000_000i128);
+    trading_post_client.trade_exact_out(
+        &farmer,
+        &broccoli.address(),
+        &4000i128,
+        &1_000_000i128,
+        &(env.ledger().sequence() + 10),
+        &false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")]
+fn test_cannot_add_liquidity_to_minted_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_cannot_leave_pool_mode_with_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Mint);
+}
+
+#[test]
+fn test_remove_vegetables_clears_config_but_keeps_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+    kale_sac_client.mint(&provider, &1000i128);
+    broccoli_sac_client.mint(&provider, &4000i128);
+    trading_post_client.add_liquidity(&provider, &broccoli.address(), &1000i128, &4000i128, &0i128);
+
+    trading_post_client.set_prices(&admin, &cabbage.address(), &25_000, &20_000);
+    trading_post_client.set_fees(&admin, &Some(cabbage.address()), &100, &250);
+    trading_post_client.set_vegetable_kind(&admin, &cabbage.address(), &VegetableKind::Reserve);
+    trading_post_client.set_limits(
+        &admin,
+        &cabbage.address(),
+        &Limits {
+            mint_cap: Some(100),
+            ..Default::default()
+        },
+    );
+
+    trading_post_client.remove_vegetables(&vec![&env, broccoli.address(), cabbage.address()]);
+
+    // the cabbage is back to the defaults
+    assert_eq!(
+        trading_post_client.prices(&cabbage.address()),
+        ExchangeRates {
+            buy_price: 10_000,
+            sell_price: 10_000,
+        }
+    );
+    assert_eq!(
+        trading_post_client.fee_info(&cabbage.address()),
+        FeeInfo {
+            buy_fee: 0,
+            sell_fee: 0,
+            destination: FeeDestination::Burn,
+        }
+    );
+    assert_eq!(
+        trading_post_client.vegetable_kind(&cabbage.address()),
+        VegetableKind::StellarAsset
+    );
+    assert_eq!(
+        trading_post_client.limits(&cabbage.address()),
+        Limits::default()
+    );
+    assert_eq!(
+        trading_post_client.trade_mode(&cabbage.address()),
+        TradeMode::Mint
+    );
+
+    // the broccoli pool stays put until the provider takes their liquidity out
+    assert_eq!(
+        trading_post_client.trade_mode(&broccoli.address()),
+        TradeMode::Pool
+    );
+    assert_eq!(
+        trading_post_client.remove_liquidity(
+            &provider,
+            &broccoli.address(),
+            &2000i128,
+            &0i128,
+            &0i128
+        ),
+        (1000, 4000)
+    );
+    assert_eq!(1000i128, kale_client.balance(&provider));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_cannot_set_trade_mode_when_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    trading_post_client.set_trade_mode(&admin, &broccoli.address(), &TradeMode::Pool);
+}
+
+#[test]
+fn test_trade_mintable_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let turnip = env.register(FarmToken, (&admin,));
+    let turnip_client = FarmTokenClient::new(&env, &turnip);
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, turnip.clone()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    assert_eq!(
+        trading_post_client.vegetable_kind(&turnip),
+        VegetableKind::StellarAsset
+    );
+    trading_post_client.set_vegetable_kind(&admin, &turnip, &VegetableKind::Mintable);
+    assert_eq!(
+        trading_post_client.vegetable_kind(&turnip),
+        VegetableKind::Mintable
+    );
+
+    // hand the custom token over to the trading post
+    env.as_contract(&turnip, || {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &trading_post_address);
+    });
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+
+    // buy turnips, which are minted by the custom token
+    trading_post_client.trade(&farmer, &turnip, &25i128, &false);
+    assert_eq!(75i128, kale_client.balance(&farmer));
+    assert_eq!(25i128, turnip_client.balance(&farmer));
+
+    // and sell some back, which burns them
+    trading_post_client.trade(&farmer, &turnip, &10i128, &true);
+    assert_eq!(85i128, kale_client.balance(&farmer));
+    assert_eq!(15i128, turnip_client.balance(&farmer));
+    assert_eq!(
+        trading_post_client.inventory(&turnip),
+        Inventory {
+            minted: 25,
+            burned: 10,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_cannot_open_without_mintable_admin_permissions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let turnip = env.register(FarmToken, (&admin,));
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, turnip.clone()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_vegetable_kind(&admin, &turnip, &VegetableKind::Mintable);
+    trading_post_client.open();
+}
+
+#[test]
+fn test_trade_reserve_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    // the trading post isn't the SAC admin, it just holds a stock of broccoli
+    trading_post_client.set_vegetable_kind(&admin, &broccoli.address(), &VegetableKind::Reserve);
+    broccoli_sac_client.mint(&trading_post_address, &100i128);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+
+    trading_post_client.trade(&farmer, &broccoli.address(), &25i128, &false);
+    assert_eq!(75i128, kale_client.balance(&farmer));
+    assert_eq!(25i128, broccoli_client.balance(&farmer));
+    assert_eq!(75i128, broccoli_client.balance(&trading_post_address));
+
+    // selling it back returns it to the stock, rather than burning it
+    trading_post_client.trade(&farmer, &broccoli.address(), &10i128, &true);
+    assert_eq!(85i128, kale_client.balance(&farmer));
+    assert_eq!(15i128, broccoli_client.balance(&farmer));
+    assert_eq!(85i128, broccoli_client.balance(&trading_post_address));
+    assert_eq!(
+        trading_post_client.inventory(&broccoli.address()),
+        Inventory::default()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+fn test_cannot_trade_for_more_than_reserve_vegetable_stock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_vegetable_kind(&admin, &broccoli.address(), &VegetableKind::Reserve);
+    broccoli_sac_client.mint(&trading_post_address, &20i128);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+    trading_post_client.trade(&farmer, &broccoli.address(), &25i128, &false);
+}
+
+#[test]
+fn test_opening_hours() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_client = token::Client::new(&env, &kale.address());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let opening_hours = vec![
+        &env,
+        Season {
+            start_ledger: 100,
+            end_ledger: 200,
+        },
+        Season {
+            start_ledger: 300,
+            end_ledger: 400,
+        },
+    ];
+    trading_post_client.set_opening_hours(&admin, &opening_hours);
+
+    // open ahead of time, and the trading post waits for its hours
+    broccoli_sac_client.set_admin(&trading_post_address);
+    env.ledger().set_sequence_number(50);
+    trading_post_client.open();
+    assert_eq!(
+        trading_post_client.schedule(),
+        Schedule {
+            is_open: true,
+            is_trading: false,
+            opening_hours: opening_hours.clone(),
+            seasons: Map::new(&env),
+        }
+    );
+
+    kale_sac_client.mint(&farmer, &100i128);
+
+    env.ledger().set_sequence_number(150);
+    assert!(trading_post_client.schedule().is_trading);
+    trading_post_client.trade(&farmer, &broccoli.address(), &25i128, &false);
+    assert_eq!(75i128, kale_client.balance(&farmer));
+    assert_eq!(25i128, broccoli_client.balance(&farmer));
+
+    // between windows, and again in the next one
+    env.ledger().set_sequence_number(200);
+    assert!(!trading_post_client.schedule().is_trading);
+    env.ledger().set_sequence_number(399);
+    trading_post_client.trade(&farmer, &broccoli.address(), &25i128, &false);
+    assert_eq!(50i128, broccoli_client.balance(&farmer));
+
+    // dropping the schedule leaves it trading whenever it's open
+    env.ledger().set_sequence_number(1000);
+    trading_post_client.set_opening_hours(&admin, &vec![&env]);
+    assert!(trading_post_client.schedule().is_trading);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_cannot_trade_outside_opening_hours() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_opening_hours(
+        &admin,
+        &vec![
+            &env,
+            Season {
+                start_ledger: 100,
+                end_ledger: 200,
+            },
+        ],
+    );
+    broccoli_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+
+    env.ledger().set_sequence_number(200);
+    trading_post_client.trade(&farmer, &broccoli.address(), &25i128, &false);
+}
+
+#[test]
+fn test_vegetable_season() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let kale_sac_client = token::StellarAssetClient::new(&env, &kale.address());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let broccoli_client = token::Client::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    let season = Season {
+        start_ledger: 100,
+        end_ledger: 200,
+    };
+    trading_post_client.set_season(&admin, &broccoli.address(), &Some(season.clone()));
+    assert_eq!(
+        trading_post_client.schedule().seasons,
+        map![&env, (broccoli.address(), season)]
+    );
+
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    kale_sac_client.mint(&farmer, &100i128);
+
+    // cabbage is in season all year round, broccoli only for its season
+    env.ledger().set_sequence_number(50);
+    trading_post_client.trade(&farmer, &cabbage.address(), &25i128, &false);
+    env.ledger().set_sequence_number(150);
+    trading_post_client.trade(&farmer, &broccoli.address(), &25i128, &false);
+    assert_eq!(25i128, broccoli_client.balance(&farmer));
+
+    // and all year round again once its season is cleared
+    trading_post_client.set_season(&admin, &broccoli.address(), &None);
+    env.ledger().set_sequence_number(500);
+    trading_post_client.trade(&farmer, &broccoli.address(), &25i128, &false);
+    assert_eq!(50i128, broccoli_client.balance(&farmer));
+    assert_eq!(trading_post_client.schedule().seasons, Map::new(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+fn test_cannot_swap_out_of_season_vegetable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli = env.register_stellar_asset_contract_v2(admin.clone());
+    let broccoli_sac_client = token::StellarAssetClient::new(&env, &broccoli.address());
+    let cabbage = env.register_stellar_asset_contract_v2(admin.clone());
+    let cabbage_sac_client = token::StellarAssetClient::new(&env, &cabbage.address());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            vec![&env, broccoli.address(), cabbage.address()],
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_season(
+        &admin,
+        &cabbage.address(),
+        &Some(Season {
+            start_ledger: 100,
+            end_ledger: 200,
+        }),
+    );
+    broccoli_sac_client.mint(&farmer, &100i128);
+    broccoli_sac_client.set_admin(&trading_post_address);
+    cabbage_sac_client.set_admin(&trading_post_address);
+    trading_post_client.open();
+
+    env.ledger().set_sequence_number(250);
+    trading_post_client.swap(
+        &farmer,
+        &broccoli.address(),
+        &cabbage.address(),
+        &50i128,
+        &0i128,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+fn test_cannot_set_backwards_opening_hours() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // set up some users
+    let admin = Address::generate(&env);
+
+    // set up some assets
+    let kale = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let trading_post_address = env.register(
+        TradingPostContract,
+        (
+            &admin,
+            &kale.address(),
+            Vec::<Address>::new(&env),
+            None::<u32>,
+        ),
+    );
+    let trading_post_client = TradingPostContractClient::new(&env, &trading_post_address);
+
+    trading_post_client.set_opening_hours(
+        &admin,
+        &vec![
+            &env,
+            Season {
+                start_ledger: 200,
+                end_ledger: 200,
+            },
+        ],
+    );
 }
@@ -1,11 +1,193 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address, Map, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub address: Address,
+    pub expiration_ledger: u32,
+}
+
+/// The prices, in basis points of `KALE` per unit of vegetable, the trading
+/// post charges when selling a vegetable and pays when buying one back.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExchangeRates {
+    pub buy_price: u32,  // KALE bps a customer pays per vegetable
+    pub sell_price: u32, // KALE bps a customer receives per vegetable
+}
+
+/// Trading fees, in basis points of the `KALE` changing hands in each
+/// direction of a trade.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Fees {
+    pub buy_fee: u32,  // taken from the KALE a customer pays for a vegetable
+    pub sell_fee: u32, // taken from the KALE a customer receives for a vegetable
+}
+
+/// Where the trading post sends the fees it collects.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeDestination {
+    Burn,
+    Recipient(Address),
+}
+
+/// The fees that apply to trades of a particular vegetable.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeInfo {
+    pub buy_fee: u32,
+    pub sell_fee: u32,
+    pub destination: FeeDestination,
+}
+
+/// How the trading post trades a vegetable against `KALE`. In `Mint` mode it
+/// issues and takes back the vegetable at its set prices, according to its
+/// `VegetableKind`. In `Pool` mode it trades from reserves of `KALE` and the
+/// vegetable put up by liquidity providers, priced along a constant-product
+/// curve.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TradeMode {
+    #[default]
+    Mint,
+    Pool,
+}
+
+/// How the trading post issues a `Mint` mode vegetable, and takes it back. A
+/// `StellarAsset` is a SAC the trading post is the admin of, and a `Mintable`
+/// is a custom token it can mint through the `Mintable` interface; both are
+/// minted and burned. A `Reserve` vegetable is only ever transferred, handed
+/// out from and taken back into the trading post's own holdings.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum VegetableKind {
+    #[default]
+    StellarAsset,
+    Mintable,
+    Reserve,
+}
+
+/// A run of ledgers, from `start_ledger` up to but not including
+/// `end_ledger`, during which the trading post trades, or a vegetable is in
+/// season.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// When the trading post trades, and which of its vegetables only do so for
+/// part of the year.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schedule {
+    pub is_open: bool,                 // whether the trading post has been opened
+    pub is_trading: bool,              // whether it's open, and within its hours, right now
+    pub opening_hours: Vec<Season>,    // the ledgers it trades in, or always if empty
+    pub seasons: Map<Address, Season>, // the vegetables which are only sometimes in season
+}
+
+/// The reserves held for a `Pool` mode vegetable, and the liquidity provider
+/// shares issued against them.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pool {
+    pub kale_reserve: i128,
+    pub vegetable_reserve: i128,
+    pub total_shares: i128,
+}
+
+/// Limits on how much of a vegetable the trading post will mint, and hand out
+/// however it's issued. A limit of `None` is switched off.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Limits {
+    pub mint_cap: Option<i128>, // most that can be minted and not yet burned
+    pub window_ledgers: u32,    // length of a volume window, in ledgers
+    pub window_volume: Option<i128>, // most handed out to all customers in one window
+    pub customer_window_volume: Option<i128>, // most handed out to one customer in one window
+}
+
+/// The running totals of a vegetable the trading post has minted and burned.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Inventory {
+    pub minted: i128,
+    pub burned: i128,
+}
+
+/// The amounts of a vegetable handed out in the most recent buckets of a
+/// rolling window, newest first, where `bucket` is the ledger sequence divided
+/// by the length of a bucket.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Window {
+    pub bucket: u32,
+    pub volumes: Vec<i128>,
+}
+
+/// How much more of a vegetable the trading post will mint, and hand out,
+/// before reaching each of its limits. `None` means that limit is switched off.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Capacity {
+    pub mint_cap: Option<i128>,
+    pub window: Option<i128>,
+    pub customer_window: Option<i128>,
+}
+
+/// The `KALE` held by the trading post, how much of it belongs to vegetable
+/// pools, the least it must keep of its own, and how much more than that it
+/// holds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reserves {
+    pub balance: i128,
+    pub pooled: i128, // held in vegetable pools, so not the trading post's own
+    pub floor: i128,
+    pub available: i128,
+}
+
+/// Each role grants access to a group of the owner functions. The owner
+/// implicitly holds every role.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Upgrader,     // upgrade
+    Operator,     // open, close, set opening hours
+    Treasurer,    // withdraw/burn KALE, set the reserve floor and fees
+    ShelfManager, // add/remove vegetables, grow/shrink shelf space, set prices, limits, modes, kinds and seasons
+}
 
 #[contracttype]
 #[derive(Clone)]
 pub enum Storage {
-    Owner,         // : address
-    KaleAddress,   // : address
-    Vegetables,    // : vec<address>
-    MaxVegetables, // : u32
-    IsOpen,        // : bool
+    Owner,              // : address
+    PendingAdmin,       // : PendingAdmin
+    Roles(Address),     // : vec<Role>
+    KaleAddress,        // : address
+    Vegetables,         // : vec<address>
+    MaxVegetables,      // : u32
+    IsOpen,             // : bool
+    Rates(Address),     // : ExchangeRates
+    DefaultFees,        // : Fees
+    Fees(Address),      // : Fees
+    FeeDestination,     // : FeeDestination
+    Limits(Address),    // : Limits
+    Inventory(Address), // : Inventory
+    Volume(Address),    // : Window
+    ReserveFloor,       // : i128
+    Mode(Address),      // : TradeMode
+    Pool(Address),      // : Pool
+    PooledKale,         // : i128
+    Kind(Address),      // : VegetableKind
+    OpeningHours,       // : vec<Season>
+    Season(Address),    // : Season
+    // : i128, in persistent storage, keyed by vegetable then provider
+    Shares(Address, Address),
+    // : Window, in temporary storage, keyed by vegetable then customer
+    CustomerVolume(Address, Address),
 }